@@ -0,0 +1,132 @@
+//! Absolute temperatures, which are different from temperature differences.
+
+use crate::{units, Quantity};
+use core::{fmt, ops};
+
+/// A difference between two temperatures, in kelvin.
+///
+/// This is a normal [`Quantity`], so it can be added to and subtracted from
+/// other temperature differences.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{values::K, TemperatureDelta};
+///
+/// let delta: TemperatureDelta = 2.0 * K - 0.625 * K;
+/// assert_eq!(format!("{:.1}", delta), "1.4 * K");
+/// ```
+pub type TemperatureDelta = Quantity<{ units::K }>;
+
+/// An absolute temperature, in kelvin.
+///
+/// Adding two absolute temperatures is physically meaningless, so a `Temperature`
+/// can only be subtracted from another `Temperature`, which yields a [`TemperatureDelta`],
+/// or shifted by a `TemperatureDelta`.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{values::K, Temperature};
+///
+/// let morning = Temperature::new(280.0);
+/// let noon = morning + 15.0 * K;
+/// assert_eq!(noon, Temperature::new(295.0));
+/// assert_eq!(noon - morning, 15.0 * K);
+/// assert_eq!(noon - 5.0 * K, Temperature::new(290.0));
+/// ```
+///
+/// Adding two absolute temperatures doesn't compile:
+///
+/// ```compile_fail
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::Temperature;
+///
+/// let sum = Temperature::new(300.0) + Temperature::new(300.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Temperature {
+    /// The raw value of this temperature in kelvin.
+    pub value: f64,
+}
+
+impl Temperature {
+    /// Create a new absolute temperature from a value in kelvin.
+    #[must_use]
+    pub const fn new(value: f64) -> Self {
+        Self { value }
+    }
+}
+
+impl fmt::Display for Temperature {
+    /// Format the value in kelvin, followed by the unit.
+    ///
+    /// All formatting flags, like width and precision, are applied to the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::Temperature;
+    ///
+    /// assert_eq!(format!("{}", Temperature::new(293.15)), "293.15 * K");
+    /// assert_eq!(format!("{:.1}", Temperature::new(293.16)), "293.2 * K");
+    /// assert_eq!(format!("{:7.2}", Temperature::new(4.2)), "   4.20 * K");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " * {}", units::K)
+    }
+}
+
+impl ops::Sub<Temperature> for Temperature {
+    type Output = TemperatureDelta;
+
+    /// Calculate the difference between two absolute temperatures.
+    fn sub(self, rhs: Temperature) -> Self::Output {
+        Quantity::new(self.value - rhs.value)
+    }
+}
+
+impl ops::Add<TemperatureDelta> for Temperature {
+    type Output = Self;
+
+    /// Shift this temperature by a temperature difference.
+    fn add(self, rhs: TemperatureDelta) -> Self::Output {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl ops::Add<Temperature> for TemperatureDelta {
+    type Output = Temperature;
+
+    /// Shift a temperature by this temperature difference.
+    fn add(self, rhs: Temperature) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl ops::AddAssign<TemperatureDelta> for Temperature {
+    fn add_assign(&mut self, rhs: TemperatureDelta) {
+        self.value += rhs.value;
+    }
+}
+
+impl ops::Sub<TemperatureDelta> for Temperature {
+    type Output = Self;
+
+    /// Shift this temperature by a negative temperature difference.
+    fn sub(self, rhs: TemperatureDelta) -> Self::Output {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl ops::SubAssign<TemperatureDelta> for Temperature {
+    fn sub_assign(&mut self, rhs: TemperatureDelta) {
+        self.value -= rhs.value;
+    }
+}
@@ -0,0 +1,349 @@
+//! Quantities whose unit is only known at runtime.
+
+use crate::{Quantity, Unit};
+use core::{convert::TryFrom, fmt, ops};
+
+/// The error that is returned if two units don't match at runtime.
+///
+/// The `Display` implementation prints both units.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use std::convert::TryFrom;
+/// use tiny_uom::{units, DynQuantity, Quantity};
+///
+/// let velocity = DynQuantity::new(5.0, units::m / units::s);
+/// let err = Quantity::<{ units::m }>::try_from(velocity).unwrap_err();
+/// assert_eq!(err.to_string(), "expected unit `m`, found `m * s^-1`");
+///
+/// let err = Quantity::<{ units::N }>::try_from(DynQuantity::new(1.0, units::J)).unwrap_err();
+/// assert_eq!(err.to_string(), "expected unit `m * kg * s^-2`, found `m^2 * kg * s^-2`");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnitMismatch {
+    /// The unit that was expected.
+    pub expected: Unit,
+    /// The unit that was actually found.
+    pub found: Unit,
+}
+
+impl fmt::Display for UnitMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected unit `{}`, found `{}`",
+            self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitMismatch {}
+
+/// A `DynQuantity` is a raw value and it's unit, where the unit
+/// is stored at runtime instead of being a const generic parameter.
+///
+/// This is useful if the unit of a value is not known at compile time,
+/// for example if it's read from a file. A `DynQuantity` can be converted
+/// into a [`Quantity`] using `TryFrom`, which checks the unit at runtime.
+///
+/// Multiplying a number with a [`Quantity`] constant from the [`values`](crate::values)
+/// module creates a `Quantity`, where the unit is checked at compile time. Multiplying a
+/// number with a [`Unit`] constant from the [`units`](crate::units) module creates a `DynQuantity`,
+/// where the unit is checked at runtime.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use std::convert::TryFrom;
+/// use tiny_uom::{units, values::{m, s}, DynQuantity, Quantity, UnitMismatch};
+///
+/// let distance = DynQuantity::new(10.0, units::m);
+/// let time = 2.0 * units::s;
+///
+/// let velocity = distance / time;
+/// assert_eq!(velocity, DynQuantity::new(5.0, units::m / units::s));
+/// assert_eq!(Quantity::<{ units::m.div(units::s) }>::try_from(velocity), Ok(5.0 * (m / s)));
+///
+/// assert_eq!(
+///     Quantity::<{ units::m }>::try_from(velocity),
+///     Err(UnitMismatch {
+///         expected: units::m,
+///         found: units::m / units::s,
+///     })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynQuantity {
+    /// The raw value of this `DynQuantity`
+    pub value: f64,
+    /// The unit of this `DynQuantity`
+    pub unit: Unit,
+}
+
+impl DynQuantity {
+    /// Create a new `DynQuantity` with the given value and unit.
+    #[must_use]
+    pub const fn new(value: f64, unit: Unit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Return the canonical representation of this quantity in SI base units.
+    ///
+    /// The value of a `DynQuantity` is always stored in SI base units,
+    /// so this is a no-op. Scaled units, like `km`, are already converted
+    /// into their base unit when creating the quantity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units, values::km, DynQuantity};
+    ///
+    /// let distance = DynQuantity::from(5.0 * km);
+    /// assert_eq!(distance.normalized(), DynQuantity::new(5000.0, units::m));
+    /// ```
+    #[must_use]
+    pub const fn normalized(self) -> Self {
+        self
+    }
+
+    /// Add two quantities, if they have the same unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the units of both quantities are different.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units, DynQuantity};
+    ///
+    /// let a = DynQuantity::new(1.0, units::m);
+    /// assert_eq!(a.try_add(a), Ok(DynQuantity::new(2.0, units::m)));
+    /// assert!(a.try_add(DynQuantity::new(1.0, units::s)).is_err());
+    /// ```
+    pub fn try_add(self, rhs: Self) -> Result<Self, UnitMismatch> {
+        self.check_unit(rhs.unit)?;
+        Ok(Self::new(self.value + rhs.value, self.unit))
+    }
+
+    /// Subtract two quantities, if they have the same unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the units of both quantities are different.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units, DynQuantity, UnitMismatch};
+    ///
+    /// let a = DynQuantity::new(1.0, units::m);
+    /// assert_eq!(a.try_sub(a), Ok(DynQuantity::new(0.0, units::m)));
+    /// assert_eq!(
+    ///     a.try_sub(DynQuantity::new(1.0, units::s)),
+    ///     Err(UnitMismatch {
+    ///         expected: units::m,
+    ///         found: units::s,
+    ///     })
+    /// );
+    /// ```
+    pub fn try_sub(self, rhs: Self) -> Result<Self, UnitMismatch> {
+        self.check_unit(rhs.unit)?;
+        Ok(Self::new(self.value - rhs.value, self.unit))
+    }
+
+    /// Return the value of this quantity expressed in `target`, where `factor`
+    /// is the value of one `target` in SI base units.
+    ///
+    /// This can be used to convert into units that are not part of this crate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this quantity doesn't have the same dimension as `target`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units, DynQuantity, UnitMismatch};
+    ///
+    /// const FOOT: f64 = 0.3048;
+    ///
+    /// let length = DynQuantity::new(3.048, units::m);
+    /// assert_eq!(length.try_into_unit(units::m, FOOT), Ok(10.0));
+    /// assert_eq!(
+    ///     length.try_into_unit(units::s, 60.0),
+    ///     Err(UnitMismatch {
+    ///         expected: units::s,
+    ///         found: units::m,
+    ///     })
+    /// );
+    /// ```
+    pub fn try_into_unit(self, target: Unit, factor: f64) -> Result<f64, UnitMismatch> {
+        if self.unit == target {
+            Ok(self.value / factor)
+        } else {
+            Err(UnitMismatch {
+                expected: target,
+                found: self.unit,
+            })
+        }
+    }
+
+    /// Make sure, that `found` is the same unit as the unit of this quantity.
+    fn check_unit(self, found: Unit) -> Result<(), UnitMismatch> {
+        if self.unit == found {
+            Ok(())
+        } else {
+            Err(UnitMismatch {
+                expected: self.unit,
+                found,
+            })
+        }
+    }
+}
+
+impl fmt::Display for DynQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " * {}", self.unit)
+    }
+}
+
+impl<const U: Unit> From<Quantity<U>> for DynQuantity {
+    fn from(quantity: Quantity<U>) -> Self {
+        Self::new(quantity.value, U)
+    }
+}
+
+impl<const U: Unit> TryFrom<DynQuantity> for Quantity<U> {
+    type Error = UnitMismatch;
+
+    /// Convert the `DynQuantity` into a `Quantity`, if the units are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use std::convert::TryFrom;
+    /// use tiny_uom::{units, values::s, DynQuantity, Quantity, UnitMismatch};
+    ///
+    /// let time = DynQuantity::new(2.0, units::s);
+    /// assert_eq!(Quantity::<{ units::s }>::try_from(time), Ok(2.0 * s));
+    /// assert_eq!(
+    ///     Quantity::<{ units::A }>::try_from(time),
+    ///     Err(UnitMismatch {
+    ///         expected: units::A,
+    ///         found: units::s,
+    ///     })
+    /// );
+    /// ```
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        if quantity.unit == U {
+            Ok(Quantity::new(quantity.value))
+        } else {
+            Err(UnitMismatch {
+                expected: U,
+                found: quantity.unit,
+            })
+        }
+    }
+}
+
+impl<const U: Unit> From<Quantity<U>> for (f64, Unit) {
+    fn from(quantity: Quantity<U>) -> Self {
+        (quantity.value, U)
+    }
+}
+
+impl<const U: Unit> TryFrom<(f64, Unit)> for Quantity<U> {
+    type Error = UnitMismatch;
+
+    /// Convert a value and it's unit into a `Quantity`, if the units are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use std::convert::TryFrom;
+    /// use tiny_uom::{units, values::m, Quantity};
+    ///
+    /// let raw: (f64, _) = (5.0 * m).into();
+    /// assert_eq!(raw, (5.0, units::m));
+    /// assert_eq!(Quantity::<{ units::m }>::try_from(raw), Ok(5.0 * m));
+    /// assert!(Quantity::<{ units::s }>::try_from(raw).is_err());
+    /// ```
+    fn try_from((value, unit): (f64, Unit)) -> Result<Self, Self::Error> {
+        Self::try_from(DynQuantity::new(value, unit))
+    }
+}
+
+impl ops::Mul<DynQuantity> for DynQuantity {
+    type Output = Self;
+
+    /// Multiply two units and their values.
+    fn mul(self, rhs: DynQuantity) -> Self::Output {
+        Self::new(self.value * rhs.value, self.unit.mul(rhs.unit))
+    }
+}
+
+impl ops::Mul<f64> for DynQuantity {
+    type Output = Self;
+
+    /// Multiply the value of this unit with a number.
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs, self.unit)
+    }
+}
+
+impl ops::Mul<DynQuantity> for f64 {
+    type Output = DynQuantity;
+
+    /// Multiply the value of this unit with a number.
+    fn mul(self, rhs: DynQuantity) -> Self::Output {
+        DynQuantity::new(self * rhs.value, rhs.unit)
+    }
+}
+
+impl ops::Mul<Unit> for f64 {
+    type Output = DynQuantity;
+
+    /// Create a `DynQuantity` using this number as the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units, DynQuantity};
+    ///
+    /// assert_eq!(5.0 * units::m, DynQuantity::new(5.0, units::m));
+    /// assert_eq!(5.0 * (units::m / units::s), DynQuantity::new(5.0, units::m / units::s));
+    /// ```
+    fn mul(self, rhs: Unit) -> Self::Output {
+        DynQuantity::new(self, rhs)
+    }
+}
+
+impl ops::Div<DynQuantity> for DynQuantity {
+    type Output = Self;
+
+    /// Divides two units and their values.
+    fn div(self, rhs: DynQuantity) -> Self::Output {
+        Self::new(self.value / rhs.value, self.unit.div(rhs.unit))
+    }
+}
+
+impl ops::Div<f64> for DynQuantity {
+    type Output = Self;
+
+    /// Divides the value of this unit with a number.
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs, self.unit)
+    }
+}
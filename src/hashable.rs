@@ -0,0 +1,64 @@
+//! Wrapper around quantities that can be hashed.
+
+use crate::{Quantity, Unit};
+use core::hash::{Hash, Hasher};
+
+/// A wrapper around a [`Quantity`] that implements `Eq` and `Hash`.
+///
+/// Two `OrderedQuantity`s are equal, if the bit patterns of their values are equal.
+/// To make this consistent, all NaN values are treated as the same value,
+/// and `-0.0` is treated as the same value as `0.0`.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use std::collections::HashSet;
+/// use tiny_uom::{values::m, OrderedQuantity};
+///
+/// let mut buckets = HashSet::new();
+/// buckets.insert(OrderedQuantity(1.0 * m));
+/// buckets.insert(OrderedQuantity(0.5 * m + 0.5 * m));
+/// buckets.insert(OrderedQuantity(f64::NAN * m));
+/// buckets.insert(OrderedQuantity(-f64::NAN * m));
+/// buckets.insert(OrderedQuantity(0.0 * m));
+/// buckets.insert(OrderedQuantity(-0.0 * m));
+/// assert_eq!(buckets.len(), 3);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedQuantity<const U: Unit>(pub Quantity<U>);
+
+impl<const U: Unit> OrderedQuantity<U> {
+    /// Return the canonical bit pattern of the value, which is used for comparing and hashing.
+    fn bits(self) -> u64 {
+        let value = self.0.value;
+        if value.is_nan() {
+            f64::NAN.to_bits()
+        } else if value == 0.0 {
+            0.0_f64.to_bits()
+        } else {
+            value.to_bits()
+        }
+    }
+}
+
+impl<const U: Unit> PartialEq for OrderedQuantity<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl<const U: Unit> Eq for OrderedQuantity<U> {}
+
+impl<const U: Unit> Hash for OrderedQuantity<U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+impl<const U: Unit> From<Quantity<U>> for OrderedQuantity<U> {
+    fn from(quantity: Quantity<U>) -> Self {
+        Self(quantity)
+    }
+}
@@ -36,12 +36,245 @@
     unsafe_code
 )]
 #![allow(incomplete_features)]
-#![feature(const_generics, const_evaluatable_checked)]
+#![feature(
+    const_checked_int_methods,
+    const_generics,
+    const_evaluatable_checked,
+    const_panic,
+    step_trait,
+    step_trait_ext,
+    total_cmp
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::{fmt, ops};
+
+mod absolute;
+pub use absolute::{Temperature, TemperatureDelta};
+
+mod dynamic;
+pub use dynamic::{DynQuantity, UnitMismatch};
 
-use std::{fmt, ops};
+mod hashable;
+pub use hashable::OrderedQuantity;
+
+pub mod prelude;
+
+#[cfg(feature = "std")]
+mod parsing;
+#[cfg(feature = "std")]
+pub use parsing::{ParseQuantityError, ParseUnitError};
 
 mod si;
-pub use si::{units, values};
+pub use si::{prefixes, quantities, units, values, values32, values_cgs, values_i64};
+
+/// Define custom units that are derived from the SI units.
+///
+/// For every unit, this macro generates a [`Unit`] constant inside a `units` module,
+/// and a [`Quantity`] constant with value `1.0` inside a `values` module.
+/// Both modules are created in the module where the macro is invoked.
+///
+/// The unit expression is evaluated in a const context and can use
+/// all constants from the [`units`] module of this crate.
+///
+/// The expression can be prefixed with a literal factor, like `0.1 * Pa_s`,
+/// to define a unit that is a scaled version of a SI unit. Like the scaled
+/// [`values`], such a unit only gets a `Quantity` constant that has the factor
+/// as its value, because a `Unit` constant can't store the factor.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// mod custom {
+///     tiny_uom::define_unit! {
+///         /// Heat capacity in joule per kelvin
+///         J_K => J.div(K),
+///         /// Molar concentration in mole per cubic metre
+///         molarity => mol.div(m.powi(3)),
+///         /// Dynamic viscosity in poise, which is the same as `g / (cm * s)`
+///         poise => 0.1 * kg.div(m.mul(s)),
+///     }
+/// }
+///
+/// use custom::values::{molarity, poise, J_K};
+/// use tiny_uom::values::{cm, g, m, mol, s, J, K, Pa_s};
+///
+/// assert_eq!(6.0 * J / (3.0 * K), 2.0 * J_K);
+/// assert_eq!(4.0 * mol / (2.0 * m * m * m), 2.0 * molarity);
+/// assert_eq!(10.0 * poise, 1.0 * Pa_s);
+/// assert!((1.0 * g / (cm * s)).abs_diff_eq(poise, 1e-12 * poise));
+/// ```
+///
+/// Scaled units are not part of the `units` module:
+///
+/// ```compile_fail
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// mod custom {
+///     tiny_uom::define_unit! {
+///         /// Dynamic viscosity in poise
+///         poise => 0.1 * Pa_s,
+///     }
+/// }
+///
+/// let viscosity = 2.0 * custom::units::poise;
+/// ```
+#[macro_export]
+macro_rules! define_unit {
+    (@munch [$($units:tt)*] [$($values:tt)*]
+        $(#[$attr:meta])*
+        $name:ident => $factor:literal * $unit:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::define_unit! {
+            @munch
+            [$($units)*]
+            [$($values)* $(#[$attr])* $name => $factor * $unit;]
+            $($($rest)*)?
+        }
+    };
+    (@munch [$($units:tt)*] [$($values:tt)*]
+        $(#[$attr:meta])*
+        $name:ident => $unit:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::define_unit! {
+            @munch
+            [$($units)* $(#[$attr])* $name => $unit;]
+            [$($values)* $(#[$attr])* $name => 1.0 * super::units::$name;]
+            $($($rest)*)?
+        }
+    };
+    (@munch
+        [$($(#[$unit_attr:meta])* $unit_name:ident => $unit:expr;)*]
+        [$($(#[$value_attr:meta])* $value_name:ident => $factor:literal * $value_unit:expr;)*]
+    ) => {
+        /// Custom unit constants.
+        #[allow(non_upper_case_globals)]
+        pub mod units {
+            #[allow(unused_imports)]
+            use $crate::units::*;
+
+            $(
+                $(#[$unit_attr])*
+                pub const $unit_name: $crate::Unit = $unit;
+            )*
+        }
+
+        /// Custom unit constants wrapped in `Quantity`.
+        #[allow(non_upper_case_globals)]
+        pub mod values {
+            #[allow(unused_imports)]
+            use $crate::units::*;
+
+            $(
+                $(#[$value_attr])*
+                pub const $value_name: $crate::Quantity<{ $value_unit }> =
+                    $crate::Quantity::new($factor);
+            )*
+        }
+    };
+    ($($input:tt)*) => {
+        $crate::define_unit! { @munch [] [] $($input)* }
+    };
+}
+
+/// Assert at compile time, that a unit is equal to an expected unit.
+///
+/// This is useful inside generic functions, to make sure that a const generic
+/// unit parameter has a specific unit. The assertion is checked when the
+/// function is instantiated, and fails compilation if the units are different.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{assert_unit, units, values::N, Quantity, Unit};
+///
+/// fn force<const U: Unit>(quantity: Quantity<U>) -> f64 {
+///     assert_unit!(U, units::N);
+///     quantity.value
+/// }
+///
+/// assert_eq!(force(5.0 * N), 5.0);
+/// ```
+///
+/// Calling the function with a different unit doesn't compile:
+///
+/// ```compile_fail
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{assert_unit, units, values::m, Quantity, Unit};
+///
+/// fn force<const U: Unit>(quantity: Quantity<U>) -> f64 {
+///     assert_unit!(U, units::N);
+///     quantity.value
+/// }
+///
+/// force(5.0 * m);
+/// ```
+#[macro_export]
+macro_rules! assert_unit {
+    ($unit:expr, $expected:expr $(,)?) => {
+        let () = $crate::AssertUnitEq::<{ $unit }, { $expected }>::OK;
+    };
+}
+
+/// Helper type for the [`assert_unit`] macro.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct AssertUnitEq<const A: Unit, const B: Unit>;
+
+impl<const A: Unit, const B: Unit> AssertUnitEq<A, B> {
+    /// Evaluating this constant fails, if the units are not equal.
+    pub const OK: () = if !A.eq(B) {
+        panic!("the units are not equal")
+    };
+}
+
+/// A marker trait that is implemented for every quantity type with the unit `D`.
+///
+/// This can be used to bound generic functions on the dimension of a quantity,
+/// without repeating the unit expression in the signature.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values::{km, m, s}, HasDimension, Quantity, Unit};
+///
+/// fn speed_in_km_per_h<const U: Unit>(velocity: Quantity<U>) -> f64
+/// where
+///     Quantity<U>: HasDimension<{ units::m.div(units::s) }>,
+/// {
+///     velocity.value * 3.6
+/// }
+///
+/// assert_eq!(speed_in_km_per_h(10.0 * (m / s)), 36.0);
+/// assert_eq!(speed_in_km_per_h(km / (1000.0 * s)), 3.6);
+/// ```
+///
+/// Calling the function with a quantity of a different dimension doesn't compile:
+///
+/// ```compile_fail
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values::m, HasDimension, Quantity, Unit};
+///
+/// fn speed_in_km_per_h<const U: Unit>(velocity: Quantity<U>) -> f64
+/// where
+///     Quantity<U>: HasDimension<{ units::m.div(units::s) }>,
+/// {
+///     velocity.value * 3.6
+/// }
+///
+/// speed_in_km_per_h(10.0 * m);
+/// ```
+pub trait HasDimension<const D: Unit> {}
 
 /// The `Unit` struct can represent every possible unit
 /// that is defined in the [`SI`] system.
@@ -66,8 +299,32 @@ pub use si::{units, values};
 /// }
 /// ```
 ///
+/// ## Unit registry
+///
+/// `Unit` implements `Hash`, so it can be used as the key of a map:
+/// ```
+/// use std::collections::HashSet;
+/// use tiny_uom::units::{kg, m, s, N};
+///
+/// let units = [N, kg * m / (s * s), m, m, s];
+/// let set = units.iter().copied().collect::<HashSet<_>>();
+/// assert_eq!(set.len(), 3);
+/// ```
+///
+/// ## Ordering
+///
+/// Units are ordered by comparing their exponents lexicographically,
+/// in the order `m`, `kg`, `s`, `A`, `K`, `mol`, `cd`:
+/// ```
+/// use tiny_uom::units::{kg, m, s, N};
+///
+/// let mut units = vec![N, s, m * m, kg, m];
+/// units.sort();
+/// assert_eq!(units, [s, kg, m, N, m * m]);
+/// ```
+///
 /// [`SI`]: https://jcgm.bipm.org/vim/en/1.16.html
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[allow(non_snake_case)]
 pub struct Unit {
     pub(crate) m: i8,
@@ -80,7 +337,94 @@ pub struct Unit {
 }
 
 impl Unit {
+    /// Create a unit from the exponents of all base units.
+    ///
+    /// The exponents are in the same order as returned by [`exponents`](Unit::exponents),
+    /// which is `m`, `kg`, `s`, `A`, `K`, `mol`, `cd`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units::N, Unit};
+    ///
+    /// const NEWTON: Unit = Unit::from_exponents([1, 1, -2, 0, 0, 0, 0]);
+    /// assert_eq!(NEWTON, N);
+    /// assert_eq!(Unit::from_exponents(N.exponents()), N);
+    /// ```
+    #[must_use]
+    pub const fn from_exponents(exps: [i8; 7]) -> Self {
+        Self {
+            m: exps[0],
+            kg: exps[1],
+            s: exps[2],
+            A: exps[3],
+            K: exps[4],
+            mol: exps[5],
+            cd: exps[6],
+        }
+    }
+
+    /// Return the exponents of all base units.
+    ///
+    /// The exponents are in the order `m`, `kg`, `s`, `A`, `K`, `mol`, `cd`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::N;
+    ///
+    /// assert_eq!(N.exponents(), [1, 1, -2, 0, 0, 0, 0]);
+    /// assert_eq!(N.second_exp(), -2);
+    /// ```
+    #[must_use]
+    pub const fn exponents(self) -> [i8; 7] {
+        [self.m, self.kg, self.s, self.A, self.K, self.mol, self.cd]
+    }
+
+    /// Return the exponent of the metre (`m`) base unit.
+    #[must_use]
+    pub const fn metre_exp(self) -> i8 {
+        self.m
+    }
+
+    /// Return the exponent of the kilogram (`kg`) base unit.
+    #[must_use]
+    pub const fn kilogram_exp(self) -> i8 {
+        self.kg
+    }
+
+    /// Return the exponent of the second (`s`) base unit.
+    #[must_use]
+    pub const fn second_exp(self) -> i8 {
+        self.s
+    }
+
+    /// Return the exponent of the ampere (`A`) base unit.
+    #[must_use]
+    pub const fn ampere_exp(self) -> i8 {
+        self.A
+    }
+
+    /// Return the exponent of the kelvin (`K`) base unit.
+    #[must_use]
+    pub const fn kelvin_exp(self) -> i8 {
+        self.K
+    }
+
+    /// Return the exponent of the mole (`mol`) base unit.
+    #[must_use]
+    pub const fn mole_exp(self) -> i8 {
+        self.mol
+    }
+
+    /// Return the exponent of the candela (`cd`) base unit.
+    #[must_use]
+    pub const fn candela_exp(self) -> i8 {
+        self.cd
+    }
+
     /// Invert this unit by negating all exponents.
+    #[must_use]
     pub const fn inv(self) -> Self {
         Self {
             m: -self.m,
@@ -94,6 +438,14 @@ impl Unit {
     }
 
     /// Multiply two units and return the resulting unit.
+    ///
+    /// # Overflow
+    ///
+    /// The exponents are stored as `i8`, so they can overflow.
+    /// Overflowing exponents fail compilation if this method is used in a const context,
+    /// like the unit of a quantity, panic in debug builds, and wrap around in release builds.
+    /// Use [`checked_mul`](Unit::checked_mul) to detect overflows.
+    #[must_use]
     pub const fn mul(self, rhs: Self) -> Self {
         Self {
             m: self.m + rhs.m,
@@ -107,6 +459,14 @@ impl Unit {
     }
 
     /// Divide two units and return the resulting unit.
+    ///
+    /// # Overflow
+    ///
+    /// The exponents are stored as `i8`, so they can overflow.
+    /// Overflowing exponents fail compilation if this method is used in a const context,
+    /// like the unit of a quantity, panic in debug builds, and wrap around in release builds.
+    /// Use [`checked_div`](Unit::checked_div) to detect overflows.
+    #[must_use]
     pub const fn div(self, rhs: Self) -> Self {
         Self {
             m: self.m - rhs.m,
@@ -118,9 +478,435 @@ impl Unit {
             cd: self.cd - rhs.cd,
         }
     }
+
+    /// Raise this unit to the `n`th power by multiplying all exponents with `n`.
+    ///
+    /// This can be used in const contexts, for example to define an area
+    /// or a volume without multiplying the unit with itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units::{m, s, Hz}, Unit};
+    ///
+    /// const AREA: Unit = m.powi(2);
+    /// const VOLUME: Unit = m.powi(3);
+    /// assert_eq!(AREA, m.mul(m));
+    /// assert_eq!(VOLUME, m.mul(m).mul(m));
+    /// assert_eq!(VOLUME.exponents(), [3, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(s.powi(-1), Hz);
+    /// assert!(m.powi(0).is_dimensionless());
+    /// ```
+    ///
+    /// # Overflow
+    ///
+    /// Like [`mul`](Unit::mul), this method doesn't check if the exponents overflow.
+    /// Use [`checked_powi`](Unit::checked_powi) to detect overflows.
+    #[must_use]
+    pub const fn powi(self, n: i8) -> Self {
+        Self {
+            m: self.m * n,
+            kg: self.kg * n,
+            s: self.s * n,
+            A: self.A * n,
+            K: self.K * n,
+            mol: self.mol * n,
+            cd: self.cd * n,
+        }
+    }
+
+    /// Multiply two units, returning `None` if any exponent overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s};
+    ///
+    /// assert_eq!(m.checked_mul(s), Some(m * s));
+    /// assert_eq!(m.powi(100).checked_mul(m.powi(100)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.exponents(), rhs.exponents());
+        let mut exps = [0; 7];
+        let mut idx = 0;
+        while idx < exps.len() {
+            exps[idx] = match lhs[idx].checked_add(rhs[idx]) {
+                Some(exp) => exp,
+                None => return None,
+            };
+            idx += 1;
+        }
+        Some(Self::from_exponents(exps))
+    }
+
+    /// Divide two units, returning `None` if any exponent overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s};
+    ///
+    /// assert_eq!(m.checked_div(s), Some(m / s));
+    /// assert_eq!(m.powi(100).checked_div(m.powi(-100)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.exponents(), rhs.exponents());
+        let mut exps = [0; 7];
+        let mut idx = 0;
+        while idx < exps.len() {
+            exps[idx] = match lhs[idx].checked_sub(rhs[idx]) {
+                Some(exp) => exp,
+                None => return None,
+            };
+            idx += 1;
+        }
+        Some(Self::from_exponents(exps))
+    }
+
+    /// Raise this unit to the `n`th power, returning `None` if any exponent overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s};
+    ///
+    /// assert_eq!(m.checked_powi(3), Some(m.powi(3)));
+    /// assert_eq!(s.checked_powi(-1), Some(s.inv()));
+    /// assert_eq!(m.powi(20).checked_powi(10), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_powi(self, n: i8) -> Option<Self> {
+        let mut exps = self.exponents();
+        let mut idx = 0;
+        while idx < exps.len() {
+            exps[idx] = match exps[idx].checked_mul(n) {
+                Some(exp) => exp,
+                None => return None,
+            };
+            idx += 1;
+        }
+        Some(Self::from_exponents(exps))
+    }
+
+    /// Check if two units are equal.
+    ///
+    /// This is the same as the `PartialEq` implementation,
+    /// but can be used in const contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{kg, m, s, N};
+    ///
+    /// const IS_NEWTON: bool = N.eq(kg.mul(m).div(s.powi(2)));
+    /// assert!(IS_NEWTON);
+    /// assert!(!N.eq(kg));
+    /// ```
+    #[must_use]
+    pub const fn eq(self, other: Self) -> bool {
+        self.m == other.m
+            && self.kg == other.kg
+            && self.s == other.s
+            && self.A == other.A
+            && self.K == other.K
+            && self.mol == other.mol
+            && self.cd == other.cd
+    }
+
+    /// Return the name of the dimension of this unit, if it's a well-known dimension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{kg, m, mol, s, N};
+    ///
+    /// assert_eq!(N.dimension_name(), Some("force"));
+    /// assert_eq!((m / s).dimension_name(), Some("velocity"));
+    /// assert_eq!((m / s).inv().dimension_name(), None);
+    /// assert_eq!((kg * mol).dimension_name(), None);
+    /// ```
+    #[must_use]
+    pub fn dimension_name(self) -> Option<&'static str> {
+        DIMENSION_NAMES
+            .iter()
+            .find_map(|&(unit, name)| if unit == self { Some(name) } else { None })
+    }
+
+    /// Format this unit using Unicode superscripts for the exponents
+    /// and middle dots between the base units, e.g. `m·kg·s⁻²`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s, N};
+    ///
+    /// assert_eq!(N.pretty(), "m·kg·s⁻²");
+    /// assert_eq!(m.powi(12).pretty(), "m¹²");
+    /// assert_eq!(s.powi(-30).pretty(), "s⁻³⁰");
+    /// assert_eq!((m / m).pretty(), "");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn pretty(self) -> String {
+        const SUPERSCRIPTS: [char; 10] = [
+            '\u{2070}', '\u{b9}', '\u{b2}', '\u{b3}', '\u{2074}', '\u{2075}', '\u{2076}',
+            '\u{2077}', '\u{2078}', '\u{2079}',
+        ];
+
+        let units = [
+            ("m", self.m),
+            ("kg", self.kg),
+            ("s", self.s),
+            ("A", self.A),
+            ("K", self.K),
+            ("mol", self.mol),
+            ("cd", self.cd),
+        ];
+
+        let mut out = String::new();
+        for &(name, exp) in units.iter().filter(|unit| unit.1 != 0) {
+            if !out.is_empty() {
+                out.push('\u{b7}');
+            }
+            out.push_str(name);
+
+            if exp == 1 {
+                continue;
+            }
+            if exp < 0 {
+                out.push('\u{207b}');
+            }
+            for digit in i16::from(exp).abs().to_string().bytes() {
+                out.push(SUPERSCRIPTS[usize::from(digit - b'0')]);
+            }
+        }
+        out
+    }
+
+    /// Check if this unit is exactly one of the seven base units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{kg, m, s, N};
+    ///
+    /// const IS_BASE: bool = kg.is_base();
+    /// assert!(IS_BASE);
+    /// assert!(!N.is_base());
+    /// assert!(!m.powi(2).is_base());
+    /// assert!(!(m / m).is_base());
+    /// ```
+    #[must_use]
+    pub const fn is_base(self) -> bool {
+        self.base_index().is_some()
+    }
+
+    /// Return the index of this base unit in the [`exponents`](Unit::exponents),
+    /// or `None` if this unit is not a base unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{cd, kg, m, mol, s, A, K, N};
+    ///
+    /// for (idx, unit) in [m, kg, s, A, K, mol, cd].iter().enumerate() {
+    ///     assert_eq!(unit.base_index(), Some(idx));
+    ///     assert_eq!(unit.exponents()[idx], 1);
+    /// }
+    /// assert_eq!(N.base_index(), None);
+    /// assert_eq!(s.inv().base_index(), None);
+    /// ```
+    #[must_use]
+    pub const fn base_index(self) -> Option<usize> {
+        let exps = self.exponents();
+        let mut base = None;
+        let mut idx = 0;
+        while idx < exps.len() {
+            match exps[idx] {
+                0 => {}
+                1 if base.is_none() => base = Some(idx),
+                _ => return None,
+            }
+            idx += 1;
+        }
+        base
+    }
+
+    /// Check if all exponents of this unit are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s};
+    ///
+    /// const RATIO: bool = m.div(m).is_dimensionless();
+    /// assert!(RATIO);
+    /// assert!(!s.is_dimensionless());
+    /// ```
+    #[must_use]
+    pub const fn is_dimensionless(self) -> bool {
+        self.eq(Self {
+            m: 0,
+            kg: 0,
+            s: 0,
+            A: 0,
+            K: 0,
+            mol: 0,
+            cd: 0,
+        })
+    }
+
+    /// Take the square root of this unit by halving all exponents.
+    ///
+    /// Returns `None` if any exponent is odd, because the resulting
+    /// unit can't be represented using integer exponents.
+    #[must_use]
+    pub const fn sqrt(self) -> Option<Self> {
+        self.powf_unit(1, 2)
+    }
+
+    /// Take the cube root of this unit by dividing all exponents by three.
+    ///
+    /// Returns `None` if any exponent is not divisible by three, because the resulting
+    /// unit can't be represented using integer exponents.
+    #[must_use]
+    pub const fn cbrt(self) -> Option<Self> {
+        self.powf_unit(1, 3)
+    }
+
+    /// Raise this unit to the power of `num / den`, by multiplying all exponents
+    /// with `num` and dividing them by `den`.
+    ///
+    /// Returns `None` if `den` is zero, if any resulting exponent is not an integer,
+    /// or if any intermediate exponent overflows an `i8`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s};
+    ///
+    /// assert_eq!((m * m).powf_unit(3, 2), Some(m * m * m));
+    /// assert_eq!((m * m * m).powf_unit(1, 2), None);
+    /// assert_eq!(s.powf_unit(1, 0), None);
+    /// assert_eq!(m.powi(100).powf_unit(2, 4), None);
+    /// assert_eq!(m.powi(i8::MIN).powf_unit(1, -1), None);
+    /// ```
+    #[must_use]
+    pub const fn powf_unit(self, num: i8, den: i8) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let mut exps = match self.checked_powi(num) {
+            Some(unit) => unit.exponents(),
+            None => return None,
+        };
+        let mut idx = 0;
+        while idx < exps.len() {
+            exps[idx] = match (exps[idx].checked_rem(den), exps[idx].checked_div(den)) {
+                (Some(0), Some(exp)) => exp,
+                _ => return None,
+            };
+            idx += 1;
+        }
+        Some(Self::from_exponents(exps))
+    }
+
+    /// Same as [`Unit::sqrt`], but fails const evaluation if any exponent is odd.
+    ///
+    /// Used in where clauses to make sure `Quantity::sqrt` only compiles for valid units.
+    #[cfg(feature = "std")]
+    pub(crate) const fn sqrt_unwrap(self) -> Self {
+        match self.sqrt() {
+            Some(unit) => unit,
+            None => panic!("the square root of a unit requires all exponents to be even"),
+        }
+    }
+
+    /// Same as [`Unit::cbrt`], but fails const evaluation if any exponent
+    /// is not divisible by three.
+    ///
+    /// Used in where clauses to make sure `Quantity::cbrt` only compiles for valid units.
+    #[cfg(feature = "std")]
+    pub(crate) const fn cbrt_unwrap(self) -> Self {
+        match self.cbrt() {
+            Some(unit) => unit,
+            None => {
+                panic!("the cube root of a unit requires all exponents to be divisible by three")
+            }
+        }
+    }
+
+    /// Same as [`Unit::powf_unit`], but fails const evaluation if any resulting
+    /// exponent is not an integer.
+    ///
+    /// Used in where clauses to make sure `Quantity::powf` only compiles for valid units.
+    #[cfg(feature = "std")]
+    pub(crate) const fn powf_unit_unwrap(self, num: i8, den: i8) -> Self {
+        match self.powf_unit(num, den) {
+            Some(unit) => unit,
+            None => panic!("the resulting exponents of the unit must be integers"),
+        }
+    }
 }
 
+/// The names of all dimensions that are returned by [`Unit::dimension_name`].
+const DIMENSION_NAMES: &[(Unit, &str)] = {
+    use units::{
+        cd, kat, kg, lx, m, m2_s, mol, s, Hz, Ohm, Pa, Pa_s, A, C, F, J, K, N, ONE, S, V, W,
+    };
+
+    &[
+        (ONE, "dimensionless"),
+        (m, "length"),
+        (kg, "mass"),
+        (s, "time"),
+        (A, "electric current"),
+        (K, "temperature"),
+        (mol, "amount of substance"),
+        (cd, "luminous intensity"),
+        (m.powi(2), "area"),
+        (m.powi(3), "volume"),
+        (m.div(s), "velocity"),
+        (m.div(s.powi(2)), "acceleration"),
+        (kg.div(m.powi(3)), "density"),
+        (Hz, "frequency"),
+        (N, "force"),
+        (J, "energy"),
+        (W, "power"),
+        (Pa, "pressure"),
+        (C, "electric charge"),
+        (V, "electric potential"),
+        (Ohm, "electrical resistance"),
+        (S, "electrical conductance"),
+        (F, "capacitance"),
+        (Pa_s, "dynamic viscosity"),
+        (m2_s, "kinematic viscosity"),
+        (lx, "illuminance"),
+        (kat, "catalytic activity"),
+    ]
+};
+
 impl fmt::Display for Unit {
+    /// Format this unit as a product of all base units, e.g. `m * kg * s^-2`.
+    ///
+    /// Using the alternate flag (`{:#}`) formats the unit as a fraction instead,
+    /// where a unit without any exponents is written as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::units::{m, s, N, Pa};
+    ///
+    /// assert_eq!(format!("{}", N), "m * kg * s^-2");
+    /// assert_eq!(format!("{:#}", N), "m kg / s^2");
+    /// assert_eq!(format!("{:#}", Pa), "kg / (m s^2)");
+    /// assert_eq!(format!("{:#}", s.inv()), "1 / s");
+    /// assert_eq!(format!("{}", m / m), "");
+    /// assert_eq!(format!("{:#}", m / m), "1");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let units = [
             ("m", self.m),
@@ -131,23 +917,55 @@ impl fmt::Display for Unit {
             ("mol", self.mol),
             ("cd", self.cd),
         ];
+        let units = units.iter().copied();
+
+        if !f.alternate() {
+            return fmt_factors(f, units.filter(|unit| unit.1 != 0), " * ");
+        }
 
-        let units = units.iter().filter(|unit| unit.1 != 0);
-        let len = units.clone().count();
+        let numerator = units.clone().filter(|unit| unit.1 > 0);
+        let denominator =
+            units.filter_map(|(name, exp)| if exp < 0 { Some((name, -exp)) } else { None });
 
-        for (idx, (name, unit)) in units.enumerate() {
-            if *unit == 1 {
-                write!(f, "{}", name)?;
-            } else {
-                write!(f, "{}^{}", name, unit)?;
+        if numerator.clone().count() == 0 {
+            write!(f, "1")?;
+        } else {
+            fmt_factors(f, numerator, " ")?;
+        }
+
+        match denominator.clone().count() {
+            0 => Ok(()),
+            1 => {
+                write!(f, " / ")?;
+                fmt_factors(f, denominator, " ")
             }
-            if idx + 1 != len {
-                write!(f, " * ")?;
+            _ => {
+                write!(f, " / (")?;
+                fmt_factors(f, denominator, " ")?;
+                write!(f, ")")
             }
         }
+    }
+}
 
-        Ok(())
+/// Write a list of base units and their exponents, separated by `sep`.
+fn fmt_factors<'a>(
+    f: &mut fmt::Formatter<'_>,
+    factors: impl Iterator<Item = (&'a str, i8)>,
+    sep: &str,
+) -> fmt::Result {
+    for (idx, (name, exp)) in factors.enumerate() {
+        if idx != 0 {
+            write!(f, "{}", sep)?;
+        }
+        if exp == 1 {
+            write!(f, "{}", name)?;
+        } else {
+            write!(f, "{}^{}", name, exp)?;
+        }
     }
+
+    Ok(())
 }
 
 impl ops::Mul<Unit> for Unit {
@@ -170,75 +988,621 @@ impl ops::Div<Unit> for Unit {
 
 /// A `Quantity` represents a raw value and it's unit
 /// that is represented as a const generic parameter.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// # Example
+///
+/// Quantities of the same unit can be compared with each other:
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::values::m;
+///
+/// assert!(3.0 * m < 5.0 * m);
+/// ```
+///
+/// Comparing quantities with different units doesn't compile:
+/// ```compile_fail
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::values::{m, s};
+///
+/// assert!(3.0 * m < 5.0 * s);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 #[repr(transparent)]
 pub struct Quantity<const U: Unit> {
     /// The raw value of this `Quantity`
     pub value: f64,
 }
 
+/// The same as [`Quantity`], but the raw value is stored as a `f32`.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values32::{m as m_f32, s as s_f32}, Quantity32};
+///
+/// let distance = 4.0f32 * m_f32;
+/// assert_eq!(distance, Quantity32::<{ units::m }>::new(4.0));
+///
+/// let velocity = distance / (2.0 * s_f32);
+/// assert_eq!(velocity, 2.0 * (m_f32 / s_f32));
+/// ```
+///
+/// All methods and traits of [`Quantity`] are available for `Quantity32` too:
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values32::{m, s, ONE}, Quantity32};
+///
+/// assert!((0.0_f32 * m).is_zero());
+/// assert_eq!(7.5_f32 * m % (2.0 * m), 1.5 * m);
+/// assert_eq!((3.0_f32 * m).scale_by(2.0 * ONE), 6.0 * m);
+///
+/// let product: Quantity32<{ units::ONE }> = vec![2.0_f32 * ONE, 3.0 * ONE].into_iter().product();
+/// assert_eq!(product, Quantity32::from(6.0_f32));
+/// assert_eq!(f32::from(product), 6.0);
+///
+/// let mean = Quantity32::mean(vec![1.0_f32 * s, 2.0 * s, 6.0 * s]);
+/// assert_eq!(mean, Some(3.0 * s));
+///
+/// assert_eq!((1500.0_f32 * m).to_engineering_string(), "1.5 km");
+/// assert_eq!((0.002_f32 * s).to_engineering_string(), "2 ms");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Quantity32<const U: Unit> {
+    /// The raw value of this `Quantity32`
+    pub value: f32,
+}
+
+/// The same as [`Quantity`], but the raw value is stored as a `i64`.
+///
+/// This is useful for quantities that can be counted exactly,
+/// because there are no rounding errors. Note that dividing two
+/// `QuantityI64`s uses integer division, which rounds towards zero.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values_i64::{kg as kg_i, s as s_i}, QuantityI64};
+///
+/// let flow = 6 * kg_i / (2 * s_i);
+/// assert_eq!(flow, QuantityI64::<{ units::kg.div(units::s) }>::new(3));
+///
+/// let rounded = 7 * kg_i / (2 * s_i);
+/// assert_eq!(rounded.value, 3);
+/// ```
+///
+/// All methods and traits of [`Quantity`] that don't require floating
+/// point math are available for `QuantityI64` too:
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values_i64::{m, ONE}, QuantityI64};
+///
+/// assert!((0 * m).is_zero());
+/// assert!(!(3 * m).is_zero());
+/// assert_eq!(7 * m % (2 * m), 1 * m);
+/// assert_eq!((3 * m).scale_by(2 * ONE), 6 * m);
+///
+/// let product: QuantityI64<{ units::ONE }> = vec![2 * ONE, 3 * ONE].into_iter().product();
+/// assert_eq!(product, QuantityI64::from(6_i64));
+/// assert_eq!(i64::from(product), 6);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct QuantityI64<const U: Unit> {
+    /// The raw value of this `QuantityI64`
+    pub value: i64,
+}
+
 /// Implement all methods and traits for a quantity type.
 macro_rules! quantity_impl {
-    ($num:ty, $t:ident) => {
+    ($num:ty, $t:ident, $zero:literal, $one:literal) => {
         impl<const U: Unit> $t<U> {
-            /// Create a new `Quantity` with the given value.
+            /// Create a new quantity with the given value.
+            #[must_use]
             pub const fn new(value: $num) -> Self {
                 Self { value }
             }
-        }
 
-        impl<const U: Unit> ::std::fmt::Display for $t<U> {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                write!(f, "{} * {}", self.value, U)
+            /// Create a new quantity with value zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::m, Quantity};
+            ///
+            /// assert_eq!(Quantity::<{ units::m }>::zero().value, 0.0);
+            ///
+            /// let total = [1.0 * m, 2.0 * m].iter().copied().fold(Quantity::zero(), std::ops::Add::add);
+            /// assert_eq!(total, 3.0 * m);
+            /// ```
+            #[must_use]
+            pub const fn zero() -> Self {
+                Self { value: $zero }
             }
-        }
 
-        // ============================
-        // Add implementations
-        // ============================
-        impl<const U: Unit> ::std::ops::Add<$t<U>> for $t<U> {
-            type Output = Self;
+            /// Create a new quantity with value one, which is the unit value of `U`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::m, Quantity};
+            ///
+            /// assert_eq!(Quantity::<{ units::m }>::one(), m);
+            /// ```
+            #[must_use]
+            pub const fn one() -> Self {
+                Self { value: $one }
+            }
 
-            /// Add the value of two equal units.
-            fn add(self, rhs: Self) -> Self::Output {
-                Self {
-                    value: self.value + rhs.value,
-                }
+            /// Return the canonical representation of this quantity in SI base units.
+            ///
+            /// Quantities are always stored in SI base units, so this is a no-op.
+            /// For example `km` is stored as a quantity in metre with value `1000.0`.
+            /// This method only exists for uniformity with [`DynQuantity::normalized`].
+            #[must_use]
+            pub const fn normalized(self) -> Self {
+                self
             }
-        }
 
-        impl<const U: Unit> ::std::ops::AddAssign<$t<U>> for $t<U> {
-            /// Add the value of two equal units.
-            fn add_assign(&mut self, rhs: Self) {
-                self.value += rhs.value;
+            /// Create a new quantity from the raw value.
+            ///
+            /// This is the same as [`new`](Self::new), but makes it explicit that
+            /// the unit of the value is not checked.
+            #[must_use]
+            pub const fn from_raw(value: $num) -> Self {
+                Self { value }
             }
-        }
 
-        // ============================
-        // Sub implementations
-        // ============================
-        impl<const U: Unit> ::std::ops::Sub<$t<U>> for $t<U> {
-            type Output = Self;
+            /// Return the raw value of this quantity, without the unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::m, Quantity};
+            ///
+            /// let raw = (5.0 * m).into_raw();
+            /// assert_eq!(raw, 5.0);
+            /// assert_eq!(Quantity::<{ units::m }>::from_raw(raw), 5.0 * m);
+            /// ```
+            #[must_use]
+            pub const fn into_raw(self) -> $num {
+                self.value
+            }
 
-            /// Subtract the value of two equal units.
-            fn sub(self, rhs: Self) -> Self::Output {
-                Self {
-                    value: self.value - rhs.value,
-                }
+            /// Return `true` if the value of this quantity is greater than zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::kg;
+            ///
+            /// assert!((2.0 * kg).is_positive());
+            /// assert!(!(-2.0 * kg).is_positive());
+            /// assert!(!(0.0 * kg).is_positive());
+            /// assert!(!(-0.0 * kg).is_positive());
+            /// ```
+            #[must_use]
+            pub fn is_positive(self) -> bool {
+                self.value > $zero
             }
-        }
 
-        impl<const U: Unit> ::std::ops::SubAssign<$t<U>> for $t<U> {
-            /// Subtract the value of two equal units.
-            fn sub_assign(&mut self, rhs: Self) {
-                self.value -= rhs.value;
+            /// Return `true` if the value of this quantity is less than zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::kg;
+            ///
+            /// assert!((-2.0 * kg).is_negative());
+            /// assert!(!(2.0 * kg).is_negative());
+            /// assert!(!(0.0 * kg).is_negative());
+            /// assert!(!(-0.0 * kg).is_negative());
+            /// ```
+            #[must_use]
+            pub fn is_negative(self) -> bool {
+                self.value < $zero
+            }
+
+            /// Return `true` if the value of this quantity is zero.
+            ///
+            /// Both, `0.0` and `-0.0`, are considered zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::kg;
+            ///
+            /// assert!((0.0 * kg).is_zero());
+            /// assert!((-0.0 * kg).is_zero());
+            /// assert!(!(2.0 * kg).is_zero());
+            /// assert!(!(-2.0 * kg).is_zero());
+            /// ```
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                self.value == $zero
+            }
+
+            /// Return the unit of this quantity as a runtime value.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::{m, s}};
+            ///
+            /// assert_eq!((5.0 * (m / s)).unit(), units::m / units::s);
+            /// ```
+            #[must_use]
+            pub const fn unit(self) -> Unit {
+                U
+            }
+
+            /// Return the raw value and the unit of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::N};
+            ///
+            /// let (value, unit) = (2.5 * N).parts();
+            /// assert_eq!(value, 2.5);
+            /// assert_eq!(unit, units::N);
+            /// assert_eq!(format!("{} {:#}", value, unit), "2.5 m kg / s^2");
+            /// ```
+            #[must_use]
+            pub const fn parts(self) -> ($num, Unit) {
+                (self.value, U)
+            }
+
+            /// Apply a function to the value of this quantity, while keeping the unit.
+            ///
+            /// The caller is responsible for making sure that the function
+            /// is dimensionally correct for the unit of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((3.0 * m).map(|x| x * 2.0), 6.0 * m);
+            /// ```
+            #[must_use]
+            pub fn map(self, f: impl FnOnce($num) -> $num) -> Self {
+                Self {
+                    value: f(self.value),
+                }
+            }
+
+            /// Return a quantity with the same unit as this quantity, but with a new value.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// let velocity = 3.0 * (m / s);
+            /// assert_eq!(velocity.with_value(5.0), 5.0 * (m / s));
+            /// ```
+            #[must_use]
+            pub const fn with_value(self, value: $num) -> Self {
+                Self { value }
+            }
+
+            /// Scale this quantity by a dimensionless factor, keeping the unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{km, m};
+            ///
+            /// let ratio = 1.0 * km / (500.0 * m);
+            /// assert_eq!((3.0 * m).scale_by(ratio), 6.0 * m);
+            /// ```
+            #[must_use]
+            pub fn scale_by(self, factor: $t<{ crate::units::ONE }>) -> Self {
+                Self {
+                    value: self.value * factor.value,
+                }
+            }
+        }
+
+        impl<const U: Unit> ::core::convert::AsRef<$num> for $t<U> {
+            /// Return a reference to the raw value of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// fn double(value: impl AsRef<f64>) -> f64 {
+            ///     value.as_ref() * 2.0
+            /// }
+            ///
+            /// assert_eq!(double(3.0 * m), 6.0);
+            /// ```
+            fn as_ref(&self) -> &$num {
+                &self.value
+            }
+        }
+
+        impl<const U: Unit> ::core::borrow::Borrow<$num> for $t<U> {
+            /// Borrow the raw value of this quantity.
+            fn borrow(&self) -> &$num {
+                &self.value
+            }
+        }
+
+        impl<const U: Unit> crate::HasDimension<U> for $t<U> {}
+
+        impl<const U: Unit> ::core::default::Default for $t<U> {
+            /// Create a new quantity with value zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, Quantity};
+            ///
+            /// assert_eq!(Quantity::<{ units::s }>::default().value, 0.0);
+            /// ```
+            fn default() -> Self {
+                Self::zero()
+            }
+        }
+
+        impl ::core::convert::From<$t<{ crate::units::ONE }>> for $num {
+            /// Convert a dimensionless quantity into a raw number.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let ratio: f64 = (10.0 * m / (2.0 * m)).into();
+            /// assert_eq!(ratio, 5.0);
+            /// ```
+            ///
+            /// Quantities that have a dimension can't be converted:
+            ///
+            /// ```compile_fail
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let length: f64 = (10.0 * m).into();
+            /// ```
+            fn from(quantity: $t<{ crate::units::ONE }>) -> Self {
+                quantity.value
+            }
+        }
+
+        impl ::core::convert::From<$num> for $t<{ crate::units::ONE }> {
+            /// Convert a raw number into a dimensionless quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, Quantity};
+            ///
+            /// let ratio = Quantity::<{ units::ONE }>::from(5.0);
+            /// assert_eq!(f64::from(ratio), 5.0);
+            /// ```
+            fn from(value: $num) -> Self {
+                Self { value }
+            }
+        }
+
+        impl<const U: Unit> ::core::fmt::Display for $t<U> {
+            /// Format the value, followed by the unit.
+            ///
+            /// All formatting flags, like width and precision, are applied to the value.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!(format!("{}", 3.5 * m), "3.5 * m");
+            /// assert_eq!(format!("{:.2}", 3.14159 * m), "3.14 * m");
+            /// assert_eq!(format!("{:6.1}", 3.14159 * m), "   3.1 * m");
+            /// assert_eq!(format!("{:06.1}", 3.14159 * m), "0003.1 * m");
+            /// ```
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.value, f)?;
+                write!(f, " * {}", U)
+            }
+        }
+
+        impl<const U: Unit> ::core::fmt::LowerExp for $t<U> {
+            /// Format the value in exponential notation, followed by the unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!(format!("{:e}", 1500.0 * m), "1.5e3 * m");
+            /// assert_eq!(format!("{:.2e}", 1500.0 * m), "1.50e3 * m");
+            /// ```
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::LowerExp::fmt(&self.value, f)?;
+                write!(f, " * {}", U)
+            }
+        }
+
+        impl<const U: Unit> ::core::fmt::UpperExp for $t<U> {
+            /// Format the value in exponential notation, followed by the unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// assert_eq!(format!("{:E}", 0.025 * (m / s)), "2.5E-2 * m * s^-1");
+            /// assert_eq!(format!("{:.1E}", 1500.0 * m), "1.5E3 * m");
+            /// ```
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::UpperExp::fmt(&self.value, f)?;
+                write!(f, " * {}", U)
+            }
+        }
+
+        // ============================
+        // Add implementations
+        // ============================
+        impl<const U: Unit> ::core::ops::Add<$t<U>> for $t<U> {
+            type Output = Self;
+
+            /// Add the value of two equal units.
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    value: self.value + rhs.value,
+                }
+            }
+        }
+
+        impl<const U: Unit> ::core::ops::AddAssign<$t<U>> for $t<U> {
+            /// Add the value of two equal units.
+            fn add_assign(&mut self, rhs: Self) {
+                self.value += rhs.value;
+            }
+        }
+
+        // ============================
+        // Sub implementations
+        // ============================
+        impl<const U: Unit> ::core::ops::Sub<$t<U>> for $t<U> {
+            type Output = Self;
+
+            /// Subtract the value of two equal units.
+            ///
+            /// Subtracting quantities with different units doesn't compile.
+            /// Use [`DynQuantity::try_sub`](crate::DynQuantity::try_sub) if the units
+            /// are only known at runtime.
+            ///
+            /// ```compile_fail
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// let nonsense = 1.0 * m - 1.0 * s;
+            /// ```
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    value: self.value - rhs.value,
+                }
+            }
+        }
+
+        impl<const U: Unit> ::core::ops::SubAssign<$t<U>> for $t<U> {
+            /// Subtract the value of two equal units.
+            fn sub_assign(&mut self, rhs: Self) {
+                self.value -= rhs.value;
+            }
+        }
+
+        // ============================
+        // Neg implementations
+        // ============================
+        impl<const U: Unit> ::core::ops::Neg for $t<U> {
+            type Output = Self;
+
+            /// Negate the value of this unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!(-(5.0 * m), -5.0 * m);
+            /// ```
+            fn neg(self) -> Self::Output {
+                Self { value: -self.value }
+            }
+        }
+
+        // ============================
+        // Rem implementations
+        // ============================
+        impl<const U: Unit> ::core::ops::Rem<$t<U>> for $t<U> {
+            type Output = Self;
+
+            /// Calculate the remainder of dividing two equal units.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::s;
+            ///
+            /// assert_eq!((7.0 * s) % (3.0 * s), 1.0 * s);
+            /// assert_eq!((-7.0 * s) % (3.0 * s), -1.0 * s);
+            /// ```
+            fn rem(self, rhs: Self) -> Self::Output {
+                Self {
+                    value: self.value % rhs.value,
+                }
+            }
+        }
+
+        impl<const U: Unit> ::core::ops::RemAssign<$t<U>> for $t<U> {
+            /// Calculate the remainder of dividing two equal units.
+            fn rem_assign(&mut self, rhs: Self) {
+                self.value %= rhs.value;
             }
         }
 
         // ============================
         // Mul implementations
         // ============================
-        impl<const U: Unit> ::std::ops::Mul<$num> for $t<U> {
+        impl<const U: Unit> ::core::ops::Mul<$num> for $t<U> {
             type Output = Self;
 
             /// Multiply the value of this unit with a number.
@@ -249,7 +1613,7 @@ macro_rules! quantity_impl {
             }
         }
 
-        impl<const U: Unit> ::std::ops::Mul<$t<U>> for $num {
+        impl<const U: Unit> ::core::ops::Mul<$t<U>> for $num {
             type Output = $t<U>;
 
             /// Multiply the value of this unit with a number.
@@ -260,7 +1624,7 @@ macro_rules! quantity_impl {
             }
         }
 
-        impl<const L: Unit, const R: Unit> ::std::ops::Mul<$t<R>> for $t<L>
+        impl<const L: Unit, const R: Unit> ::core::ops::Mul<$t<R>> for $t<L>
         where
             $t<{ L.mul(R) }>: ,
         {
@@ -274,17 +1638,73 @@ macro_rules! quantity_impl {
             }
         }
 
-        impl<const U: Unit> ::std::ops::MulAssign<$num> for $t<U> {
+        impl<const U: Unit> ::core::ops::Mul<$num> for &$t<U> {
+            type Output = $t<U>;
+
+            /// Multiply the value of this unit with a number.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use std::ops::Mul;
+            /// use tiny_uom::values::m;
+            ///
+            /// fn double<T>(x: &T) -> T
+            /// where
+            ///     for<'a> &'a T: Mul<f64, Output = T>,
+            /// {
+            ///     x * 2.0
+            /// }
+            ///
+            /// assert_eq!(double(&(3.0 * m)), 6.0 * m);
+            /// assert_eq!(2.0 * &(3.0 * m), 6.0 * m);
+            /// ```
+            fn mul(self, rhs: $num) -> Self::Output {
+                *self * rhs
+            }
+        }
+
+        impl<const U: Unit> ::core::ops::Mul<&$t<U>> for $num {
+            type Output = $t<U>;
+
+            /// Multiply the value of this unit with a number.
+            fn mul(self, rhs: &$t<U>) -> Self::Output {
+                self * *rhs
+            }
+        }
+
+        impl<const U: Unit> ::core::ops::MulAssign<$num> for $t<U> {
             /// Multiply the value of this unit with a number.
             fn mul_assign(&mut self, rhs: $num) {
                 self.value *= rhs;
             }
         }
 
+        impl<const U: Unit> ::core::ops::MulAssign<$t<{ crate::units::ONE }>> for $t<U> {
+            /// Multiply the value of this unit with a dimensionless factor.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let mut length = 2.0 * m;
+            /// length *= 3.0 * m / (2.0 * m);
+            /// assert_eq!(length, 3.0 * m);
+            /// ```
+            fn mul_assign(&mut self, rhs: $t<{ crate::units::ONE }>) {
+                self.value *= rhs.value;
+            }
+        }
+
         // ============================
         // Div implementations
         // ============================
-        impl<const U: Unit> ::std::ops::Div<$num> for $t<U> {
+        impl<const U: Unit> ::core::ops::Div<$num> for $t<U> {
             type Output = Self;
 
             /// Divides the value of this unit with a number.
@@ -295,7 +1715,7 @@ macro_rules! quantity_impl {
             }
         }
 
-        impl<const L: Unit, const R: Unit> ::std::ops::Div<$t<R>> for $t<L>
+        impl<const L: Unit, const R: Unit> ::core::ops::Div<$t<R>> for $t<L>
         where
             $t<{ L.div(R) }>: ,
         {
@@ -309,7 +1729,7 @@ macro_rules! quantity_impl {
             }
         }
 
-        impl<const U: Unit> ::std::ops::Div<$t<U>> for $num
+        impl<const U: Unit> ::core::ops::Div<$t<U>> for $num
         where
             $t<{ U.inv() }>: ,
         {
@@ -322,13 +1742,1531 @@ macro_rules! quantity_impl {
             }
         }
 
-        impl<const U: Unit> ::std::ops::DivAssign<$num> for $t<U> {
+        impl<const U: Unit> ::core::ops::Div<$num> for &$t<U> {
+            type Output = $t<U>;
+
+            /// Divides the value of this unit with a number.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let length = 3.0 * m;
+            /// assert_eq!(&length / 2.0, 1.5 * m);
+            /// ```
+            fn div(self, rhs: $num) -> Self::Output {
+                *self / rhs
+            }
+        }
+
+        impl<const U: Unit> ::core::ops::DivAssign<$num> for $t<U> {
             /// Divides the value of this unit with a number.
             fn div_assign(&mut self, rhs: $num) {
                 self.value /= rhs;
             }
         }
+
+        impl<const U: Unit> ::core::ops::DivAssign<$t<{ crate::units::ONE }>> for $t<U> {
+            /// Divides the value of this unit with a dimensionless factor.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// let mut time = 3.0 * s;
+            /// time /= 3.0 * m / (2.0 * m);
+            /// assert_eq!(time, 2.0 * s);
+            /// ```
+            fn div_assign(&mut self, rhs: $t<{ crate::units::ONE }>) {
+                self.value /= rhs.value;
+            }
+        }
+
+        // ============================
+        // Sum implementations
+        // ============================
+        impl<const U: Unit> ::core::iter::Sum for $t<U> {
+            /// Sum up all quantities, starting at zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::m, Quantity};
+            ///
+            /// let distances = vec![1.0 * m, 2.0 * m, 3.0 * m];
+            /// let total: Quantity<{ units::m }> = distances.iter().sum();
+            /// assert_eq!(total, 6.0 * m);
+            ///
+            /// let empty = Vec::<Quantity<{ units::m }>>::new();
+            /// let total: Quantity<{ units::m }> = empty.into_iter().sum();
+            /// assert_eq!(total, 0.0 * m);
+            /// ```
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<'a, const U: Unit> ::core::iter::Sum<&'a $t<U>> for $t<U> {
+            /// Sum up all quantities, starting at zero.
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + *x)
+            }
+        }
+
+        // ============================
+        // Product implementations
+        // ============================
+        impl ::core::iter::Product for $t<{ crate::units::ONE }> {
+            /// Multiply all dimensionless quantities, starting at one.
+            ///
+            /// Only dimensionless quantities can be multiplied this way,
+            /// because the product of other quantities would change the unit with every element.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::m, Quantity};
+            ///
+            /// let ratios = vec![4.0 * m / (2.0 * m), 3.0 * m / (1.0 * m), 1.0 * m / (4.0 * m)];
+            /// let total: Quantity<{ units::ONE }> = ratios.into_iter().product();
+            /// assert_eq!(f64::from(total), 1.5);
+            ///
+            /// let empty = Vec::<Quantity<{ units::ONE }>>::new();
+            /// let total: Quantity<{ units::ONE }> = empty.into_iter().product();
+            /// assert_eq!(f64::from(total), 1.0);
+            /// ```
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| Self {
+                    value: acc.value * x.value,
+                })
+            }
+        }
     };
 }
 
-quantity_impl!(f64, Quantity);
+/// Implement all methods for a quantity type that is backed by a float.
+macro_rules! float_quantity_impl {
+    ($num:ty, $t:ident) => {
+        impl<const U: Unit> $t<U> {
+            /// Raise this quantity to the `N`th power.
+            ///
+            /// Both, the value and the unit, are raised to the power of `N`,
+            /// so negative exponents will invert the unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// assert_eq!((3.0 * m).powi::<3>(), 27.0 * (m * m * m));
+            /// assert_eq!((2.0 * s).powi::<-1>(), 0.5 / s);
+            /// assert_eq!((2.0 * m).powi::<0>(), m / m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn powi<const N: i8>(self) -> $t<{ U.powi(N) }>
+            where
+                $t<{ U.powi(N) }>: ,
+            {
+                $t {
+                    value: self.value.powi(N.into()),
+                }
+            }
+
+            /// Take the square root of this quantity.
+            ///
+            /// The exponents of the unit are halved, which means that every exponent
+            /// of `U` must be even. Calling this method on a quantity with an odd exponent
+            /// is a compile-time error, because the resulting unit can't be represented
+            /// with integer exponents.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((9.0 * m * m).sqrt(), 3.0 * m);
+            /// ```
+            ///
+            /// ```compile_fail
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let _ = (9.0 * m * m * m).sqrt();
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn sqrt(self) -> $t<{ U.sqrt_unwrap() }>
+            where
+                $t<{ U.sqrt_unwrap() }>: ,
+            {
+                $t {
+                    value: self.value.sqrt(),
+                }
+            }
+
+            /// Take the cube root of this quantity.
+            ///
+            /// The exponents of the unit are divided by three, which means that every
+            /// exponent of `U` must be divisible by three. Calling this method on any other
+            /// quantity is a compile-time error, because the resulting unit can't be
+            /// represented with integer exponents.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let length = (27.0 * m * m * m).cbrt();
+            /// assert!(length.abs_diff_eq(3.0 * m, 1e-12 * m));
+            /// ```
+            ///
+            /// ```compile_fail
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let _ = (9.0 * m * m).cbrt();
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn cbrt(self) -> $t<{ U.cbrt_unwrap() }>
+            where
+                $t<{ U.cbrt_unwrap() }>: ,
+            {
+                $t {
+                    value: self.value.cbrt(),
+                }
+            }
+
+            /// Compute the absolute value of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((-3.0 * m).abs(), 3.0 * m);
+            /// assert!((f64::NAN * m).abs().value.is_nan());
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                Self {
+                    value: self.value.abs(),
+                }
+            }
+
+            /// Compute the absolute difference between two quantities.
+            ///
+            /// Unlike the deprecated `f64::abs_sub`, this returns `(self - other).abs()`,
+            /// so the result is never negative.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((1.5 * m).abs_sub(1.25 * m), 0.25 * m);
+            /// assert_eq!((1.25 * m).abs_sub(1.5 * m), 0.25 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn abs_sub(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+
+            /// Return the sign of this quantity as a dimensionless number.
+            ///
+            /// See the `signum` method of the underlying number type for details.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((-3.0 * m).signum(), -1.0);
+            /// assert_eq!((3.0 * m).signum(), 1.0);
+            /// assert!((f64::NAN * m).signum().is_nan());
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn signum(self) -> $num {
+                self.value.signum()
+            }
+
+            /// Return a quantity with the magnitude of `self` and the sign of `sign`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((3.0 * m).copysign(-1.0), -3.0 * m);
+            /// assert_eq!((-3.0 * m).copysign(0.0), 3.0 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn copysign(self, sign: $num) -> Self {
+                Self {
+                    value: self.value.copysign(sign),
+                }
+            }
+
+            /// Return the value of this quantity expressed as a multiple of `reference`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{km, m, s};
+            ///
+            /// assert_eq!((5000.0 * m).value_in(km), 5.0);
+            ///
+            /// let speed = 10.0 * (m / s);
+            /// assert_eq!(speed.value_in(km / (3600.0 * s)), 36.0);
+            /// ```
+            #[must_use]
+            pub fn value_in(self, reference: Self) -> $num {
+                self.value / reference.value
+            }
+
+            /// Return the conversion factor from `other` to this quantity.
+            ///
+            /// This is the same as [`value_in`](Self::value_in), but reads better
+            /// when both quantities are unit constants.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{h, km, m, min, s};
+            ///
+            /// assert_eq!(km.factor_to(m), 1000.0);
+            /// assert_eq!(m.factor_to(km), 0.001);
+            /// assert_eq!(h.factor_to(min), 60.0);
+            /// assert_eq!(s.factor_to(s), 1.0);
+            /// ```
+            #[must_use]
+            pub fn factor_to(self, other: Self) -> $num {
+                self.value_in(other)
+            }
+
+            /// Return the minimum of two quantities.
+            ///
+            /// See the `min` method of the underlying number type for details.
+            #[must_use]
+            pub fn min(self, other: Self) -> Self {
+                Self {
+                    value: self.value.min(other.value),
+                }
+            }
+
+            /// Return the maximum of two quantities.
+            ///
+            /// See the `max` method of the underlying number type for details.
+            #[must_use]
+            pub fn max(self, other: Self) -> Self {
+                Self {
+                    value: self.value.max(other.value),
+                }
+            }
+
+            /// Restrict this quantity to the interval `[lo, hi]`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lo > hi`, `lo` is NaN, or `hi` is NaN.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::K;
+            ///
+            /// assert_eq!((150.0 * K).clamp(0.0 * K, 100.0 * K), 100.0 * K);
+            /// assert_eq!((-5.0 * K).clamp(0.0 * K, 100.0 * K), 0.0 * K);
+            /// assert_eq!((50.0 * K).clamp(0.0 * K, 100.0 * K), 50.0 * K);
+            /// assert_eq!((50.0 * K).min(20.0 * K), 20.0 * K);
+            /// assert_eq!((50.0 * K).max(20.0 * K), 50.0 * K);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn clamp(self, lo: Self, hi: Self) -> Self {
+                Self {
+                    value: self.value.clamp(lo.value, hi.value),
+                }
+            }
+
+            /// Restrict the raw value of this quantity to the interval `[lo, hi]`.
+            ///
+            /// Use [`clamp`](Self::clamp) if the bounds are quantities with the same unit,
+            /// and this method if the bounds are plain numbers that only make sense
+            /// for the raw value, like a fraction between `0.0` and `1.0`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lo > hi`, `lo` is NaN, or `hi` is NaN.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// let ratio = 3.0 * m / (2.0 * m);
+            /// assert_eq!(f64::from(ratio.clamp_value(0.0, 1.0)), 1.0);
+            /// assert_eq!((-2.0 * s).clamp_value(0.0, 1.0), 0.0 * s);
+            /// assert_eq!((0.5 * s).clamp_value(0.0, 1.0), 0.5 * s);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn clamp_value(self, lo: $num, hi: $num) -> Self {
+                Self {
+                    value: self.value.clamp(lo, hi),
+                }
+            }
+
+            /// Check if two quantities are equal, allowing an absolute difference of `epsilon`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// let velocity = (0.1 + 0.2) * m / (0.06 * s);
+            /// assert!(velocity.abs_diff_eq(5.0 * (m / s), 1e-9 * (m / s)));
+            /// assert!(!velocity.abs_diff_eq(5.1 * (m / s), 1e-9 * (m / s)));
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn abs_diff_eq(self, other: Self, epsilon: Self) -> bool {
+                (self.value - other.value).abs() <= epsilon.value
+            }
+
+            /// Check if two quantities are equal, allowing a difference that is relative
+            /// to the larger magnitude of both values.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert!((1000.0 * m).relative_eq(1000.1 * m, 1e-3));
+            /// assert!(!(1000.0 * m).relative_eq(1002.0 * m, 1e-3));
+            /// assert!(!(f64::NAN * m).relative_eq(f64::NAN * m, 1e-3));
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn relative_eq(self, other: Self, max_relative: $num) -> bool {
+                #[allow(clippy::float_cmp)]
+                if self.value == other.value {
+                    return true;
+                }
+
+                let largest = self.value.abs().max(other.value.abs());
+                (self.value - other.value).abs() <= largest * max_relative
+            }
+
+            /// Return the reciprocal of this quantity, which also inverts the unit.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::s, Quantity};
+            ///
+            /// let frequency = (2.0 * s).recip();
+            /// assert_eq!(frequency, Quantity::<{ units::s.inv() }>::new(0.5));
+            /// assert_eq!(frequency, 0.5 / s);
+            /// ```
+            #[must_use]
+            pub fn recip(self) -> $t<{ U.inv() }>
+            where
+                $t<{ U.inv() }>: ,
+            {
+                $t {
+                    value: self.value.recip(),
+                }
+            }
+
+            /// Round the value of this quantity to the nearest integer, rounding half-way cases away from zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((5.7 * m).round(), 6.0 * m);
+            /// assert_eq!((2.5 * m).round(), 3.0 * m);
+            /// assert_eq!((-2.5 * m).round(), -3.0 * m);
+            /// assert_eq!((2.49 * m).round(), 2.0 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn round(self) -> Self {
+                Self {
+                    value: self.value.round(),
+                }
+            }
+
+            /// Return the largest integer less than or equal to the value of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((5.7 * m).floor(), 5.0 * m);
+            /// assert_eq!((-0.5 * m).floor(), -1.0 * m);
+            /// assert_eq!((2.5 * m).floor(), 2.0 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn floor(self) -> Self {
+                Self {
+                    value: self.value.floor(),
+                }
+            }
+
+            /// Return the smallest integer greater than or equal to the value of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((5.2 * m).ceil(), 6.0 * m);
+            /// assert_eq!((-0.5 * m).ceil(), -0.0 * m);
+            /// assert_eq!((2.5 * m).ceil(), 3.0 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn ceil(self) -> Self {
+                Self {
+                    value: self.value.ceil(),
+                }
+            }
+
+            /// Return the integer part of the value of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((5.7 * m).trunc(), 5.0 * m);
+            /// assert_eq!((-2.5 * m).trunc(), -2.0 * m);
+            /// assert_eq!((2.5 * m).trunc(), 2.0 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn trunc(self) -> Self {
+                Self {
+                    value: self.value.trunc(),
+                }
+            }
+
+            /// Compute `self * a + b` with only one rounding error.
+            ///
+            /// `a` is a dimensionless factor, while `b` has the same unit as `self`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let start = 2.0 * m;
+            /// assert_eq!(start.mul_add(3.0, 1.0 * m), start * 3.0 + 1.0 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn mul_add(self, a: $num, b: Self) -> Self {
+                Self {
+                    value: self.value.mul_add(a, b.value),
+                }
+            }
+
+            /// Raise this quantity to the power of `NUM / DEN`.
+            ///
+            /// The exponents of the unit are multiplied with `NUM` and divided by `DEN`.
+            /// Calling this method is a compile-time error, if any of the resulting exponents
+            /// is not an integer, because the resulting unit couldn't be represented.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((4.0 * m * m).powf::<1, 2>(), 2.0 * m);
+            /// assert_eq!((4.0 * m * m).powf::<3, 2>(), 8.0 * m * m * m);
+            /// ```
+            ///
+            /// ```compile_fail
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let _ = (8.0 * m * m * m).powf::<1, 2>();
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn powf<const NUM: i8, const DEN: i8>(self) -> $t<{ U.powf_unit_unwrap(NUM, DEN) }>
+            where
+                $t<{ U.powf_unit_unwrap(NUM, DEN) }>: ,
+            {
+                $t {
+                    value: self.value.powf(
+                        <$num as ::core::convert::From<i8>>::from(NUM)
+                            / <$num as ::core::convert::From<i8>>::from(DEN),
+                    ),
+                }
+            }
+
+            /// Linearly interpolate between `self` and `other`, where `t` is a dimensionless fraction.
+            ///
+            /// For finite values, `t = 0` returns exactly `self` and `t = 1` returns exactly `other`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let (a, b) = (0.1 * m, 0.3 * m);
+            /// assert_eq!(a.lerp(b, 0.0), a);
+            /// assert_eq!(a.lerp(b, 1.0), b);
+            /// assert_eq!((2.0 * m).lerp(4.0 * m, 0.5), 3.0 * m);
+            /// ```
+            #[must_use]
+            pub fn lerp(self, other: Self, t: $num) -> Self {
+                Self {
+                    value: self.value * (1.0 - t) + other.value * t,
+                }
+            }
+
+            /// Return `true` if the value of this quantity is NaN.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert!((0.0 * m / (0.0 * m)).is_nan());
+            /// assert!(!(1.0 * m).is_nan());
+            /// ```
+            #[must_use]
+            pub fn is_nan(self) -> bool {
+                self.value.is_nan()
+            }
+
+            /// Return `true` if the value of this quantity is positive or negative infinity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// assert!((1.0 * m / (0.0 * s)).is_infinite());
+            /// assert!((-1.0 * m / (0.0 * s)).is_infinite());
+            /// assert!(!(1.0 * m / (2.0 * s)).is_infinite());
+            /// ```
+            #[must_use]
+            pub fn is_infinite(self) -> bool {
+                self.value.is_infinite()
+            }
+
+            /// Return `true` if the value of this quantity is neither infinite nor NaN.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// assert!((1.0 * m / (2.0 * s)).is_finite());
+            /// assert!(!(1.0 * m / (0.0 * s)).is_finite());
+            /// assert!(!(f64::NAN * m).is_finite());
+            /// ```
+            #[must_use]
+            pub fn is_finite(self) -> bool {
+                self.value.is_finite()
+            }
+
+            /// Divide two quantities, returning `None` if the value of `rhs` is zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, s};
+            ///
+            /// assert_eq!((10.0 * m).checked_div(2.0 * s), Some(5.0 * (m / s)));
+            /// assert_eq!((10.0 * m).checked_div(0.0 * s), None);
+            /// assert_eq!((10.0 * m).checked_div(-0.0 * s), None);
+            /// ```
+            #[must_use]
+            pub fn checked_div<const R: Unit>(self, rhs: $t<R>) -> Option<$t<{ U.div(R) }>>
+            where
+                $t<{ U.div(R) }>: ,
+            {
+                if rhs.value == 0.0 {
+                    None
+                } else {
+                    Some(self / rhs)
+                }
+            }
+
+            /// Format this quantity in engineering notation, using the SI prefix that keeps
+            /// the value in the interval `[1, 1000)`.
+            ///
+            /// Prefixes are only used if the unit is a single base unit, like `m` or `kg`,
+            /// and the value is finite.
+            /// Masses are prefixed based on the gram. All other quantities
+            /// are formatted like their `Display` implementation.
+            /// The micro prefix is written as `u`, like in the `um` constant.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{kg, m, s, A};
+            ///
+            /// assert_eq!((1500.0 * m).to_engineering_string(), "1.5 km");
+            /// assert_eq!((42.0 * m).to_engineering_string(), "42 m");
+            /// assert_eq!((0.002 * s).to_engineering_string(), "2 ms");
+            /// assert_eq!((2.5e-6 * A).to_engineering_string(), "2.5 uA");
+            /// assert_eq!((0.5 * kg).to_engineering_string(), "500 g");
+            /// assert_eq!((1500.0 * kg).to_engineering_string(), "1.5 Mg");
+            /// assert_eq!((0.0 * m).to_engineering_string(), "0 m");
+            /// assert_eq!((123.456e-9 * m).to_engineering_string(), "123.456 nm");
+            /// assert_eq!((0.000999 * m).to_engineering_string(), "999 um");
+            /// assert_eq!((-0.1234 * kg).to_engineering_string(), "-123.4 g");
+            /// assert_eq!((999.9999999e3 * m).to_engineering_string(), "999.9999999 km");
+            /// assert_eq!((999.999_999_999_999_999e3 * m).to_engineering_string(), "1 Mm");
+            /// assert_eq!((2e30 * s).to_engineering_string(), "2000000 Ys");
+            /// assert_eq!((2e-30 * s).to_engineering_string(), "0.000002 ys");
+            /// assert_eq!((f64::INFINITY * s).to_engineering_string(), "inf s");
+            /// assert_eq!((f64::NEG_INFINITY * kg).to_engineering_string(), "-inf kg");
+            /// assert_eq!((f64::NAN * m).to_engineering_string(), "NaN m");
+            /// assert_eq!((5.0 * (m / s)).to_engineering_string(), "5 * m * s^-1");
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            pub fn to_engineering_string(self) -> String {
+                const PREFIXES: [&str; 17] = [
+                    "y", "z", "a", "f", "p", "n", "u", "m", "", "k", "M", "G", "T", "P", "E", "Z",
+                    "Y",
+                ];
+
+                if !U.is_base() {
+                    return self.to_string();
+                }
+                if !self.value.is_finite() {
+                    return format!("{} {}", self.value, U);
+                }
+
+                // Move the decimal point of the shortest representation, instead of
+                // scaling the value, so no rounding errors show up in the output.
+                let formatted = format!("{:e}", self.value);
+                let mut parts = formatted.splitn(2, 'e');
+                let mantissa = parts.next().unwrap_or_default();
+                let exp = parts
+                    .next()
+                    .and_then(|exp| exp.parse::<i32>().ok())
+                    .unwrap_or(0);
+                let (sign, mantissa) = match mantissa.strip_prefix('-') {
+                    Some(mantissa) => ("-", mantissa),
+                    None => ("", mantissa),
+                };
+                let digits = mantissa.replace('.', "");
+
+                let (exp, symbol) = if U.eq(crate::units::kg) {
+                    (exp + 3, "g".to_string())
+                } else {
+                    (exp, U.to_string())
+                };
+                let prefix = if self.value == 0.0 {
+                    0
+                } else {
+                    exp.div_euclid(3).max(-8).min(8)
+                };
+
+                let int_len = exp - 3 * prefix + 1;
+                let len = digits.len() as i32;
+                let number = if int_len <= 0 {
+                    format!("0.{}{}", "0".repeat(-int_len as usize), digits)
+                } else if int_len >= len {
+                    format!("{}{}", digits, "0".repeat((int_len - len) as usize))
+                } else {
+                    let (int, frac) = digits.split_at(int_len as usize);
+                    format!("{}.{}", int, frac)
+                };
+
+                format!(
+                    "{}{} {}{}",
+                    sign,
+                    number,
+                    PREFIXES[(prefix + 8) as usize],
+                    symbol
+                )
+            }
+
+            /// Return the ordering between two quantities, which is total, even if NaN values are present.
+            ///
+            /// This uses the total ordering of the raw values, where negative NaNs
+            /// are ordered before all numbers, and positive NaNs are ordered after all numbers.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let mut lengths = vec![2.0 * m, f64::NAN * m, -1.0 * m, 0.5 * m];
+            /// lengths.sort_by(|a, b| a.total_cmp(b));
+            ///
+            /// assert_eq!(&lengths[..3], &[-1.0 * m, 0.5 * m, 2.0 * m]);
+            /// assert!(lengths[3].is_nan());
+            /// ```
+            #[must_use]
+            pub fn total_cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.value.total_cmp(&other.value)
+            }
+
+            /// Calculate the least non-negative remainder of dividing two quantities.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{rad, s};
+            ///
+            /// assert_eq!((7.0 * s).rem_euclid(3.0 * s), 1.0 * s);
+            /// assert_eq!((-7.0 * s).rem_euclid(3.0 * s), 2.0 * s);
+            /// assert_eq!((-1.0 * rad).rem_euclid(4.0 * rad), 3.0 * rad);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                Self {
+                    value: self.value.rem_euclid(rhs.value),
+                }
+            }
+
+            /// Check if the values of two quantities have the same bit pattern.
+            ///
+            /// Unlike `==`, two NaN values with the same bit pattern are equal,
+            /// and `0.0` and `-0.0` are different.
+            /// See [`OrderedQuantity`](crate::OrderedQuantity) for a wrapper that treats
+            /// all NaNs, and both zeros, as equal.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// let nan = f64::NAN * m;
+            /// assert!(nan != nan);
+            /// assert!(nan.bit_eq(nan));
+            ///
+            /// assert!(0.0 * m == -0.0 * m);
+            /// assert!(!(0.0 * m).bit_eq(-0.0 * m));
+            /// assert!((1.5 * m).bit_eq(1.5 * m));
+            /// ```
+            #[must_use]
+            pub fn bit_eq(self, other: Self) -> bool {
+                self.value.to_bits() == other.value.to_bits()
+            }
+
+            /// Round this quantity to the nearest multiple of `step`.
+            ///
+            /// If the value of `step` is zero, this quantity is returned unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((1.3 * m).round_to_multiple(0.5 * m), 1.5 * m);
+            /// assert_eq!((1.2 * m).round_to_multiple(0.5 * m), 1.0 * m);
+            /// assert_eq!((-1.3 * m).round_to_multiple(0.5 * m), -1.5 * m);
+            /// assert_eq!((1.3 * m).round_to_multiple(0.0 * m), 1.3 * m);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn round_to_multiple(self, step: Self) -> Self {
+                if step.value == 0.0 {
+                    return self;
+                }
+
+                Self {
+                    value: (self.value / step.value).round() * step.value,
+                }
+            }
+
+            /// Calculate the arithmetic mean of all quantities, or `None` if there are no quantities.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::{units, values::s, Quantity};
+            ///
+            /// let times = vec![1.0 * s, 2.0 * s, 6.0 * s];
+            /// assert_eq!(Quantity::mean(times), Some(3.0 * s));
+            /// assert_eq!(Quantity::<{ units::s }>::mean(Vec::new()), None);
+            /// ```
+            #[must_use]
+            #[allow(clippy::cast_precision_loss)]
+            pub fn mean<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+                let (count, sum) = iter
+                    .into_iter()
+                    .fold((0_usize, Self::zero()), |(count, sum), x| {
+                        (count + 1, sum + x)
+                    });
+
+                if count == 0 {
+                    None
+                } else {
+                    Some(sum / count as $num)
+                }
+            }
+
+            /// Calculate the length of the hypotenuse of a right-angle triangle,
+            /// where `self` and `other` are the lengths of the legs.
+            ///
+            /// This doesn't overflow for large values, like squaring both values would.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((3.0 * m).hypot(4.0 * m), 5.0 * m);
+            ///
+            /// let large = 3e200 * m;
+            /// assert!((large * large).is_infinite());
+            /// assert!(large.hypot(4e200 * m).relative_eq(5e200 * m, 1e-12));
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn hypot(self, other: Self) -> Self {
+                Self {
+                    value: self.value.hypot(other.value),
+                }
+            }
+
+            /// Compute the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+            ///
+            /// Both quantities have the same unit, so the result is a plain angle.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use std::f64::consts::{FRAC_PI_2, PI};
+            /// use tiny_uom::values::m;
+            ///
+            /// assert_eq!((0.0 * m).atan2(1.0 * m), 0.0);
+            /// assert_eq!((1.0 * m).atan2(0.0 * m), FRAC_PI_2);
+            /// assert_eq!((0.0 * m).atan2(-1.0 * m), PI);
+            /// assert_eq!((-1.0 * m).atan2(0.0 * m), -FRAC_PI_2);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn atan2(self, other: Self) -> $num {
+                self.value.atan2(other.value)
+            }
+        }
+
+        /// Trigonometric functions are only available for angles, which are dimensionless.
+        ///
+        /// ```compile_fail
+        /// # #![feature(const_generics, const_evaluatable_checked)]
+        /// # #![allow(incomplete_features)]
+        /// use tiny_uom::values::m;
+        ///
+        /// let _ = (2.0 * m).sin();
+        /// ```
+        impl $t<{ crate::units::rad }> {
+            /// Compute the sine of this angle.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use std::f64::consts::{FRAC_PI_2, PI};
+            /// use tiny_uom::values::rad;
+            ///
+            /// assert!(((FRAC_PI_2 * rad).sin() - 1.0).abs() < 1e-12);
+            /// assert!((PI * rad).sin().abs() < 1e-12);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn sin(self) -> $num {
+                self.value.sin()
+            }
+
+            /// Compute the cosine of this angle.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use std::f64::consts::PI;
+            /// use tiny_uom::values::rad;
+            ///
+            /// assert!(((PI * rad).cos() - -1.0).abs() < 1e-12);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn cos(self) -> $num {
+                self.value.cos()
+            }
+
+            /// Compute the tangent of this angle.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use std::f64::consts::FRAC_PI_4;
+            /// use tiny_uom::values::rad;
+            ///
+            /// assert!(((FRAC_PI_4 * rad).tan() - 1.0).abs() < 1e-12);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn tan(self) -> $num {
+                self.value.tan()
+            }
+        }
+
+        /// Exponential and logarithmic functions are only available for dimensionless quantities.
+        ///
+        /// ```compile_fail
+        /// # #![feature(const_generics, const_evaluatable_checked)]
+        /// # #![allow(incomplete_features)]
+        /// use tiny_uom::values::m;
+        ///
+        /// let _ = (2.0 * m).ln();
+        /// ```
+        impl $t<{ crate::units::ONE }> {
+            /// Compute `e` raised to the power of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, ONE};
+            ///
+            /// assert_eq!((0.0 * ONE).exp(), 1.0 * ONE);
+            ///
+            /// let growth = (2.0 * m / (1.0 * m)).exp();
+            /// assert!((growth.value - std::f64::consts::E.powi(2)).abs() < 1e-12);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn exp(self) -> Self {
+                Self {
+                    value: self.value.exp(),
+                }
+            }
+
+            /// Compute the natural logarithm of this quantity.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values::{m, ONE};
+            ///
+            /// assert_eq!((1.0 * ONE).ln(), 0.0 * ONE);
+            ///
+            /// let ratio = 10.0 * m / (1.0 * m);
+            /// assert!((ratio.ln().exp().value - 10.0).abs() < 1e-12);
+            /// ```
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn ln(self) -> Self {
+                Self {
+                    value: self.value.ln(),
+                }
+            }
+        }
+    };
+}
+
+/// Implement all methods for a quantity type that is backed by an integer.
+macro_rules! int_quantity_impl {
+    ($num:ty, $t:ident) => {
+        impl<const U: Unit> $t<U> {
+            /// Add two quantities, returning `None` if an overflow occurred.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values_i64::mol;
+            ///
+            /// assert_eq!(((i64::MAX - 1) * mol).checked_add(mol), Some(i64::MAX * mol));
+            /// assert_eq!((i64::MAX * mol).checked_add(mol), None);
+            /// ```
+            #[must_use]
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.value
+                    .checked_add(rhs.value)
+                    .map(|value| Self { value })
+            }
+
+            /// Subtract two quantities, returning `None` if an overflow occurred.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values_i64::mol;
+            ///
+            /// assert_eq!((i64::MAX * mol).checked_sub(mol), Some((i64::MAX - 1) * mol));
+            /// assert_eq!((i64::MIN * mol).checked_sub(mol), None);
+            /// ```
+            #[must_use]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.value
+                    .checked_sub(rhs.value)
+                    .map(|value| Self { value })
+            }
+
+            /// Add two quantities, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values_i64::mol;
+            ///
+            /// assert_eq!((2 * mol).saturating_add(3 * mol), 5 * mol);
+            /// assert_eq!((i64::MAX * mol).saturating_add(mol), i64::MAX * mol);
+            /// assert_eq!((i64::MIN * mol).saturating_add(-1 * mol), i64::MIN * mol);
+            /// ```
+            #[must_use]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self {
+                    value: self.value.saturating_add(rhs.value),
+                }
+            }
+
+            /// Subtract two quantities, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values_i64::mol;
+            ///
+            /// assert_eq!((2 * mol).saturating_sub(3 * mol), -1 * mol);
+            /// assert_eq!((i64::MIN * mol).saturating_sub(mol), i64::MIN * mol);
+            /// assert_eq!((i64::MAX * mol).saturating_sub(-1 * mol), i64::MAX * mol);
+            /// ```
+            #[must_use]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                Self {
+                    value: self.value.saturating_sub(rhs.value),
+                }
+            }
+
+            /// Calculate the least non-negative remainder of dividing two quantities.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value of `rhs` is zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![feature(const_generics, const_evaluatable_checked)]
+            /// # #![allow(incomplete_features)]
+            /// use tiny_uom::values_i64::s;
+            ///
+            /// assert_eq!((7 * s).rem_euclid(3 * s), 1 * s);
+            /// assert_eq!((-7 * s).rem_euclid(3 * s), 2 * s);
+            /// ```
+            #[must_use]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                Self {
+                    value: self.value.rem_euclid(rhs.value),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the `Step` trait for an integer quantity type,
+/// so ranges of quantities can be iterated.
+macro_rules! step_impl {
+    ($num:ty, $t:ident) => {
+        /// Ranges of integer quantities can be iterated, and yield all values with the same unit.
+        ///
+        /// The `Step` trait is unstable, so this implementation requires a nightly compiler,
+        /// like the rest of this crate. Iterating the ranges doesn't require any feature flags.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #![feature(const_generics, const_evaluatable_checked)]
+        /// # #![allow(incomplete_features)]
+        /// use tiny_uom::values_i64::s;
+        ///
+        /// let seconds = (0 * s..3 * s).collect::<Vec<_>>();
+        /// assert_eq!(seconds, vec![0 * s, 1 * s, 2 * s]);
+        /// assert_eq!((0 * s..=10 * s).step_by(5).last(), Some(10 * s));
+        /// ```
+        // SAFETY: All methods delegate to the `Step` implementation of the raw value,
+        // and the ordering of quantities is the same as the ordering of their values.
+        #[allow(unsafe_code)]
+        unsafe impl<const U: Unit> ::core::iter::Step for $t<U> {
+            fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+                <$num as ::core::iter::Step>::steps_between(&start.value, &end.value)
+            }
+
+            fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                <$num as ::core::iter::Step>::forward_checked(start.value, count)
+                    .map(|value| Self { value })
+            }
+
+            fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                <$num as ::core::iter::Step>::backward_checked(start.value, count)
+                    .map(|value| Self { value })
+            }
+        }
+    };
+}
+
+quantity_impl!(f64, Quantity, 0.0, 1.0);
+float_quantity_impl!(f64, Quantity);
+
+quantity_impl!(f32, Quantity32, 0.0, 1.0);
+float_quantity_impl!(f32, Quantity32);
+
+quantity_impl!(i64, QuantityI64, 0, 1);
+int_quantity_impl!(i64, QuantityI64);
+step_impl!(i64, QuantityI64);
+
+impl<const U: Unit> Quantity<U> {
+    /// Convert this quantity into a [`Quantity32`] with the same unit.
+    ///
+    /// The value is cast to `f32`, so precision is lost if the value
+    /// can't be represented exactly as an `f32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use tiny_uom::{values::m, values32};
+    ///
+    /// assert_eq!((2.5 * m).cast_f32(), 2.5 * values32::m);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn cast_f32(self) -> Quantity32<U> {
+        Quantity32::new(self.value as f32)
+    }
+
+    /// Multiply this quantity with a [`Quantity32`], by casting it to `f64` first.
+    ///
+    /// Casting from `f32` to `f64` is lossless, but the result can't be more
+    /// precise than the `f32` operand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use tiny_uom::{values::{m, N, J}, values32};
+    ///
+    /// assert_eq!((2.0 * N).mul_as_f64(1.5 * values32::m), 3.0 * J);
+    /// ```
+    ///
+    /// Multiplying quantities with different backends directly doesn't compile:
+    ///
+    /// ```compile_fail
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use tiny_uom::{values::N, values32};
+    ///
+    /// let _ = (2.0 * N) * (1.5 * values32::m);
+    /// ```
+    #[must_use]
+    pub fn mul_as_f64<const R: Unit>(self, rhs: Quantity32<R>) -> Quantity<{ U.mul(R) }>
+    where
+        Quantity<{ U.mul(R) }>: ,
+    {
+        self * rhs.cast_f64()
+    }
+}
+
+impl<const U: Unit> Quantity32<U> {
+    /// Convert this quantity into a [`Quantity`] with the same unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use tiny_uom::{values::m, values32};
+    ///
+    /// assert_eq!((2.5 * values32::m).cast_f64(), 2.5 * m);
+    /// ```
+    #[must_use]
+    pub fn cast_f64(self) -> Quantity<U> {
+        Quantity::new(f64::from(self.value))
+    }
+}
+
+/// Calculate the sum of the pairwise products of two slices of quantities.
+///
+/// If the slices have different lengths, the remaining elements of the longer one are ignored.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{dot, values::{m, J, N}};
+///
+/// let forces = [2.0 * N, 3.0 * N];
+/// let displacements = [1.0 * m, 4.0 * m, 5.0 * m];
+/// assert_eq!(dot(&forces, &displacements), 14.0 * J);
+/// ```
+#[must_use]
+pub fn dot<const A: Unit, const B: Unit>(
+    xs: &[Quantity<A>],
+    ys: &[Quantity<B>],
+) -> Quantity<{ A.mul(B) }>
+where
+    Quantity<{ A.mul(B) }>: ,
+{
+    xs.iter().zip(ys).map(|(&x, &y)| x * y).sum()
+}
+
+/// Calculate the weighted average of a slice of quantities.
+///
+/// Returns zero if there are no values.
+///
+/// # Panics
+///
+/// Panics if the number of values and weights is different,
+/// or if there are values but the weights sum up to zero.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{units, values::K, weighted_mean, Quantity};
+///
+/// let temperatures = [280.0 * K, 290.0 * K];
+/// assert_eq!(weighted_mean(&temperatures, &[1.0, 3.0]), 287.5 * K);
+///
+/// let empty: [Quantity<{ units::K }>; 0] = [];
+/// assert_eq!(weighted_mean(&empty, &[]), 0.0 * K);
+/// ```
+///
+/// ```should_panic
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{values::K, weighted_mean};
+///
+/// weighted_mean(&[280.0 * K, 290.0 * K], &[1.0]);
+/// ```
+///
+/// ```should_panic
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{values::K, weighted_mean};
+///
+/// weighted_mean(&[280.0 * K, 290.0 * K], &[0.0, 0.0]);
+/// ```
+///
+/// ```should_panic
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{values::K, weighted_mean};
+///
+/// weighted_mean(&[280.0 * K, 290.0 * K], &[1.0, -1.0]);
+/// ```
+#[must_use]
+pub fn weighted_mean<const U: Unit>(values: &[Quantity<U>], weights: &[f64]) -> Quantity<U> {
+    assert_eq!(
+        values.len(),
+        weights.len(),
+        "the number of values and weights must be equal"
+    );
+
+    if values.is_empty() {
+        return Quantity::zero();
+    }
+
+    let total = weights.iter().sum::<f64>();
+    assert!(total != 0.0, "the weights must not sum up to zero");
+
+    let sum = values
+        .iter()
+        .zip(weights)
+        .map(|(&value, &weight)| value * weight)
+        .sum::<Quantity<U>>();
+    sum / total
+}
+
+/// Calculate the root mean square of a slice of quantities.
+///
+/// Returns zero if there are no values.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{rms, units, values::V, Quantity};
+///
+/// assert_eq!(rms(&[3.0 * V, -3.0 * V, 3.0 * V]), 3.0 * V);
+/// assert_eq!(rms(&[1.0 * V, 7.0 * V]), 5.0 * V);
+///
+/// let empty: [Quantity<{ units::V }>; 0] = [];
+/// assert_eq!(rms(&empty), 0.0 * V);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn rms<const U: Unit>(values: &[Quantity<U>]) -> Quantity<U> {
+    if values.is_empty() {
+        return Quantity::zero();
+    }
+
+    let squares = values.iter().map(|x| x.value * x.value).sum::<f64>();
+    Quantity::new((squares / values.len() as f64).sqrt())
+}
+
+/// Calculate the geometric mean of a slice of positive quantities.
+///
+/// Returns zero if there are no values, and NaN if any value is negative.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{geometric_mean, units, values::m, Quantity};
+///
+/// let mean = geometric_mean(&[2.0 * m, 8.0 * m]);
+/// assert!(mean.abs_diff_eq(4.0 * m, 1e-12 * m));
+///
+/// let empty: [Quantity<{ units::m }>; 0] = [];
+/// assert_eq!(geometric_mean(&empty), 0.0 * m);
+/// assert!(geometric_mean(&[-1.0 * m, 1.0 * m]).is_nan());
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn geometric_mean<const U: Unit>(values: &[Quantity<U>]) -> Quantity<U> {
+    if values.is_empty() {
+        return Quantity::zero();
+    }
+
+    let logs = values.iter().map(|x| x.value.ln()).sum::<f64>();
+    Quantity::new((logs / values.len() as f64).exp())
+}
+
+/// Wrap every value of a slice into a quantity with the unit `U`.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use tiny_uom::{quantities_from_slice, units, values::m};
+///
+/// let readings = quantities_from_slice::<{ units::m }>(&[1.0, 2.0, 3.0]);
+/// assert_eq!(readings, vec![1.0 * m, 2.0 * m, 3.0 * m]);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn quantities_from_slice<const U: Unit>(values: &[f64]) -> Vec<Quantity<U>> {
+    values.iter().map(|&value| Quantity::new(value)).collect()
+}
@@ -0,0 +1,279 @@
+//! Parsing units from strings.
+
+use crate::{Quantity, Unit, UnitMismatch};
+use std::{error, fmt, str::FromStr};
+
+/// The error that is returned if parsing a [`Unit`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseUnitError {
+    /// The string contained a symbol that is not a SI base unit.
+    UnknownUnit(String),
+    /// The exponent of a unit is not a valid integer.
+    InvalidExponent(String),
+    /// The resulting exponent of the contained base unit doesn't fit into an `i8`.
+    ExponentOverflow(String),
+    /// The string contained more than one `/`.
+    MultipleDivisions,
+}
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseUnitError::UnknownUnit(unit) => write!(f, "unknown unit `{}`", unit),
+            ParseUnitError::InvalidExponent(exp) => write!(f, "invalid exponent `{}`", exp),
+            ParseUnitError::ExponentOverflow(unit) => {
+                write!(f, "exponent of unit `{}` overflows", unit)
+            }
+            ParseUnitError::MultipleDivisions => write!(f, "unit contains more than one `/`"),
+        }
+    }
+}
+
+impl error::Error for ParseUnitError {}
+
+/// The error that is returned if parsing a [`Quantity`] fails.
+///
+/// If the unit couldn't be parsed or doesn't match, the underlying error
+/// is returned as the [`source`](error::Error::source) of this error.
+///
+/// # Example
+///
+/// ```
+/// # #![feature(const_generics, const_evaluatable_checked)]
+/// # #![allow(incomplete_features)]
+/// use std::error::Error;
+/// use tiny_uom::{units, Quantity};
+///
+/// let err = "5 ft".parse::<Quantity<{ units::m }>>().unwrap_err();
+/// assert_eq!(err.to_string(), "invalid unit in quantity");
+///
+/// let source = err.source().unwrap();
+/// assert_eq!(source.to_string(), "unknown unit `ft`");
+/// assert!(source.source().is_none());
+///
+/// let err = "5 s".parse::<Quantity<{ units::m }>>().unwrap_err();
+/// assert_eq!(err.to_string(), "unit of quantity doesn't match");
+/// assert!(err.source().is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseQuantityError {
+    /// The value of the quantity is not a valid number.
+    InvalidValue(String),
+    /// The unit of the quantity couldn't be parsed.
+    InvalidUnit(ParseUnitError),
+    /// The parsed unit is different from the unit of the quantity.
+    UnitMismatch(UnitMismatch),
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseQuantityError::InvalidValue(value) => write!(f, "invalid value `{}`", value),
+            ParseQuantityError::InvalidUnit(_) => write!(f, "invalid unit in quantity"),
+            ParseQuantityError::UnitMismatch(_) => write!(f, "unit of quantity doesn't match"),
+        }
+    }
+}
+
+impl error::Error for ParseQuantityError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseQuantityError::InvalidValue(_) => None,
+            ParseQuantityError::InvalidUnit(err) => Some(err),
+            ParseQuantityError::UnitMismatch(err) => Some(err),
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    /// Parse a unit from a list of base units separated by `*` or whitespace.
+    ///
+    /// Every base unit can optionally be followed by `^` and an integer exponent.
+    /// All base units after a single `/` are divided instead of multiplied,
+    /// and can be wrapped in parentheses. A numerator of `1` is allowed,
+    /// so both forms of the `Display` implementation of `Unit` can be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tiny_uom::{units::{s, N, Pa}, ParseUnitError, Unit};
+    ///
+    /// assert_eq!("kg*m*s^-2".parse::<Unit>(), Ok(N));
+    /// assert_eq!("kg*m/s^2".parse::<Unit>(), Ok(N));
+    /// assert_eq!("1 / s".parse::<Unit>(), Ok(s.inv()));
+    /// assert_eq!("1/s".parse::<Unit>(), Ok(s.inv()));
+    ///
+    /// for unit in &[N, Pa, s.inv(), N / N] {
+    ///     assert_eq!(format!("{}", unit).parse::<Unit>(), Ok(*unit));
+    ///     assert_eq!(format!("{:#}", unit).parse::<Unit>(), Ok(*unit));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     "kg*ft".parse::<Unit>(),
+    ///     Err(ParseUnitError::UnknownUnit("ft".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     "m^x".parse::<Unit>(),
+    ///     Err(ParseUnitError::InvalidExponent("x".to_string()))
+    /// );
+    /// assert_eq!("m/s/s".parse::<Unit>(), Err(ParseUnitError::MultipleDivisions));
+    /// assert_eq!(
+    ///     "m^100 * m^100".parse::<Unit>(),
+    ///     Err(ParseUnitError::ExponentOverflow("m".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     "m^-128 / m".parse::<Unit>(),
+    ///     Err(ParseUnitError::ExponentOverflow("m".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     "s / s^-128".parse::<Unit>(),
+    ///     Err(ParseUnitError::ExponentOverflow("s".to_string()))
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut unit = Unit {
+            m: 0,
+            kg: 0,
+            s: 0,
+            A: 0,
+            K: 0,
+            mol: 0,
+            cd: 0,
+        };
+
+        let mut parts = s.split('/');
+        let numerator = parts.next().unwrap_or_default().trim();
+        let denominator = parts.next().map(|den| {
+            let den = den.trim();
+            den.strip_prefix('(')
+                .and_then(|den| den.strip_suffix(')'))
+                .unwrap_or(den)
+        });
+        if parts.next().is_some() {
+            return Err(ParseUnitError::MultipleDivisions);
+        }
+
+        let mut factors = Vec::new();
+        match numerator {
+            "" | "1" if denominator.is_none() => return Ok(unit),
+            "1" => {}
+            numerator => factors.extend(numerator.split('*').map(|factor| (factor, 1))),
+        }
+        if let Some(denominator) = denominator {
+            factors.extend(denominator.split('*').map(|factor| (factor, -1)));
+        }
+
+        let mut tokens = Vec::new();
+        for (factor, sign) in factors {
+            if factor.trim().is_empty() {
+                return Err(ParseUnitError::UnknownUnit(String::new()));
+            }
+            tokens.extend(factor.split_whitespace().map(|token| (token, sign)));
+        }
+
+        for (token, sign) in tokens {
+            let (name, exp) = match token.find('^') {
+                Some(idx) => {
+                    let exp = &token[idx + 1..];
+                    let exp = exp
+                        .parse::<i8>()
+                        .ok()
+                        .ok_or_else(|| ParseUnitError::InvalidExponent(exp.to_string()))?;
+                    (&token[..idx], exp)
+                }
+                None => (token, 1),
+            };
+
+            let field = match name {
+                "m" => &mut unit.m,
+                "kg" => &mut unit.kg,
+                "s" => &mut unit.s,
+                "A" => &mut unit.A,
+                "K" => &mut unit.K,
+                "mol" => &mut unit.mol,
+                "cd" => &mut unit.cd,
+                _ => return Err(ParseUnitError::UnknownUnit(name.to_string())),
+            };
+
+            *field = exp
+                .checked_mul(sign)
+                .and_then(|exp| field.checked_add(exp))
+                .ok_or_else(|| ParseUnitError::ExponentOverflow(name.to_string()))?;
+        }
+
+        Ok(unit)
+    }
+}
+
+impl<const U: Unit> FromStr for Quantity<U> {
+    type Err = ParseQuantityError;
+
+    /// Parse a quantity from a value, followed by whitespace and the unit.
+    ///
+    /// The unit can optionally be preceded by a `*`, and is parsed using the
+    /// `FromStr` implementation of [`Unit`]. It must be equal to the unit of
+    /// this quantity. A value without a unit is a dimensionless quantity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(const_generics, const_evaluatable_checked)]
+    /// # #![allow(incomplete_features)]
+    /// use tiny_uom::{units, values::{m, s}, ParseQuantityError, Quantity, UnitMismatch};
+    ///
+    /// assert_eq!("5 m/s".parse::<Quantity<{ units::m.div(units::s) }>>(), Ok(5.0 * (m / s)));
+    /// assert_eq!("2.5 m".parse::<Quantity<{ units::m }>>(), Ok(2.5 * m));
+    /// assert_eq!("0.5".parse::<Quantity<{ units::ONE }>>(), Ok(Quantity::new(0.5)));
+    /// assert_eq!("5 * m * s^-1".parse::<Quantity<{ units::m.div(units::s) }>>(), Ok(5.0 * (m / s)));
+    ///
+    /// let velocity = 2.5 * (m / s);
+    /// assert_eq!(format!("{}", velocity).parse(), Ok(velocity));
+    /// assert_eq!(format!("{:#}", velocity).parse(), Ok(velocity));
+    ///
+    /// let ratio = Quantity::<{ units::ONE }>::new(0.25);
+    /// assert_eq!(format!("{}", ratio).parse(), Ok(ratio));
+    /// assert_eq!(format!("{:#}", ratio).parse(), Ok(ratio));
+    ///
+    /// assert_eq!(
+    ///     "5 s".parse::<Quantity<{ units::m }>>(),
+    ///     Err(ParseQuantityError::UnitMismatch(UnitMismatch {
+    ///         expected: units::m,
+    ///         found: units::s,
+    ///     }))
+    /// );
+    /// assert_eq!(
+    ///     "five m".parse::<Quantity<{ units::m }>>(),
+    ///     Err(ParseQuantityError::InvalidValue("five".to_string()))
+    /// );
+    /// assert!("5 ft".parse::<Quantity<{ units::m }>>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, unit) = match s.find(char::is_whitespace) {
+            Some(idx) => (&s[..idx], &s[idx..]),
+            None => (s, ""),
+        };
+
+        let value = value
+            .parse::<f64>()
+            .ok()
+            .ok_or_else(|| ParseQuantityError::InvalidValue(value.to_string()))?;
+        let unit = unit.trim();
+        let unit = unit
+            .strip_prefix('*')
+            .unwrap_or(unit)
+            .parse::<Unit>()
+            .map_err(ParseQuantityError::InvalidUnit)?;
+
+        if unit == U {
+            Ok(Quantity::new(value))
+        } else {
+            Err(ParseQuantityError::UnitMismatch(UnitMismatch {
+                expected: U,
+                found: unit,
+            }))
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! The most commonly used types and constants.
+//!
+//! Importing everything from this module with `use tiny_uom::prelude::*` brings
+//! the value constants of the SI base units and the most common derived units
+//! into scope, together with the [`HasDimension`](crate::HasDimension) trait.
+//! The value constants can't be used as variable names while they are in scope.
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::prelude::*;
+//!
+//! fn kinetic(mass: Mass, velocity: Velocity) -> Energy {
+//!     0.5 * mass * velocity * velocity
+//! }
+//!
+//! let velocity = 10.0 * m / (2.0 * s);
+//! assert_eq!(kinetic(2.0 * kg, velocity), 25.0 * J);
+//! assert_eq!(velocity.unit(), units::m / units::s);
+//! ```
+
+pub use crate::{
+    quantities::{
+        Acceleration, Area, Energy, Force, Frequency, Length, Mass, Power, Pressure, Time,
+        Velocity, Volume,
+    },
+    units,
+    values::{cd, kg, m, mol, s, Hz, Pa, A, J, K, N, W},
+    DynQuantity, HasDimension, Quantity, Unit,
+};
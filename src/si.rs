@@ -2,8 +2,8 @@
 
 #![allow(non_upper_case_globals)]
 
-/// Helper macro to generate two constants for every unit,
-/// a unit const, and a value const in two different modules.
+/// Helper macro to generate constants for every unit,
+/// a unit const, and a value const for every quantity type in different modules.
 macro_rules! units_impl {
     ($(
         $(#[$attr:meta])*
@@ -39,22 +39,117 @@ macro_rules! units_impl {
                 pub const $name: Quantity<{ super::units::$name }> = Quantity::new(1.0);
             )*
         }
+
+        /// All SI base units but wrapped in [`Quantity32`](crate::Quantity32`)
+        /// with value `1.0`.
+        pub mod values32 {
+            use crate::Quantity32;
+
+            $(
+                $(#[$attr])*
+                pub const $name: Quantity32<{ super::units::$name }> = Quantity32::new(1.0);
+            )*
+        }
+
+        /// All SI base units but wrapped in [`QuantityI64`](crate::QuantityI64`)
+        /// with value `1`.
+        pub mod values_i64 {
+            use crate::QuantityI64;
+
+            $(
+                $(#[$attr])*
+                pub const $name: QuantityI64<{ super::units::$name }> = QuantityI64::new(1);
+            )*
+        }
+    };
+}
+
+/// Helper macro to generate value constants that are a scaled
+/// version of an existing unit.
+///
+/// The factor can use any of the constants from the [`prefixes`] module
+/// and the unit can be any expression using the constants from the [`units`] module.
+macro_rules! scaled_impl {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident => $factor:tt * $unit:expr
+    ),*$(,)?) => {
+        /// Value constants that are scaled versions of other units.
+        pub mod values {
+            #[allow(unused_imports)]
+            use crate::{prefixes::*, units::*, Quantity};
+
+            $(
+                $(#[$attr])*
+                pub const $name: Quantity<{ $unit }> = Quantity::new($factor);
+            )*
+        }
     };
 }
 
-units_impl! {
-    /// Time in seconds
-    s => Unit { s: 1, ..NONE },
-    /// Length in metre
-    m => Unit { m: 1, ..NONE },
-    /// Mass in kilogram
-    kg => Unit { kg: 1, ..NONE },
-    /// Electric current in ampere
-    A => Unit { A: 1, ..NONE },
-    /// Temperature in kelvin
-    K => Unit { K: 1, ..NONE },
-    /// Amount of substance in mole
-    mol => Unit { mol: 1, ..NONE },
-    /// Luminous intensity in candela
-    cd => Unit { cd: 1, ..NONE },
+mod cgs;
+mod derived;
+mod nonstandard;
+pub mod prefixes;
+pub mod quantities;
+mod scaled;
+
+/// All SI base units and more constants.
+pub mod units {
+    pub use super::base::units::*;
+    pub use super::derived::units::*;
+}
+
+/// All SI base units but wrapped in [`Quantity`](crate::Quantity`)
+/// with value `1.0`.
+///
+/// This module also contains scaled versions of the units, like `km`.
+pub mod values {
+    pub use super::base::values::*;
+    pub use super::derived::values::*;
+    pub use super::nonstandard::values::*;
+    pub use super::scaled::values::*;
+}
+
+/// All SI base units but wrapped in [`Quantity32`](crate::Quantity32`)
+/// with value `1.0`.
+pub mod values32 {
+    pub use super::base::values32::*;
+    pub use super::derived::values32::*;
+}
+
+/// All SI base units but wrapped in [`QuantityI64`](crate::QuantityI64`)
+/// with value `1`.
+pub mod values_i64 {
+    pub use super::base::values_i64::*;
+    pub use super::derived::values_i64::*;
+}
+
+/// Value constants of the centimetre-gram-second (CGS) system.
+///
+/// The values are wrapped in [`Quantity`](crate::Quantity) and stored in SI base units,
+/// so they can be combined with all other quantities.
+pub mod values_cgs {
+    pub use super::base::values::s;
+    pub use super::cgs::values::*;
+    pub use super::scaled::values::{cm, g};
+}
+
+mod base {
+    units_impl! {
+        /// Time in seconds
+        s => Unit { s: 1, ..NONE },
+        /// Length in metre
+        m => Unit { m: 1, ..NONE },
+        /// Mass in kilogram
+        kg => Unit { kg: 1, ..NONE },
+        /// Electric current in ampere
+        A => Unit { A: 1, ..NONE },
+        /// Temperature in kelvin
+        K => Unit { K: 1, ..NONE },
+        /// Amount of substance in mole
+        mol => Unit { mol: 1, ..NONE },
+        /// Luminous intensity in candela
+        cd => Unit { cd: 1, ..NONE },
+    }
 }
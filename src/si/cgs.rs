@@ -0,0 +1,41 @@
+//! Units of the centimetre-gram-second (CGS) system.
+//!
+//! All constants defined here are scaled versions of SI units and
+//! are re-exported in the [`values_cgs`](crate::values_cgs) module,
+//! so the values are still stored in SI base units. The CGS base units
+//! `cm` and `g` are the ones from the scaled SI units, and `s` is the SI second.
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::{
+//!     values::{m, J, N},
+//!     values_cgs::{cm, dyne, erg, g, s, Gal},
+//! };
+//!
+//! assert_eq!(1.0 * cm, 0.01 * m);
+//! assert_eq!(1.0 * dyne, 1e-5 * N);
+//! assert_eq!(1.0 * erg, 1e-7 * J);
+//!
+//! let force = 2.0 * g * (3.0 * cm) / (s * s);
+//! assert!(force.abs_diff_eq(6.0 * dyne, 1e-12 * dyne));
+//!
+//! let work = 4.0 * dyne * (5.0 * cm);
+//! assert!(work.abs_diff_eq(20.0 * erg, 1e-12 * erg));
+//! assert!((1.0 * Gal).abs_diff_eq(1.0 * cm / (s * s), 1e-12 * Gal));
+//! ```
+
+scaled_impl! {
+    /// Acceleration in gal, which is the same as `cm / s^2`
+    Gal => CENTI * m.div(s.powi(2)),
+    /// Force in dyne, which is the same as `g * cm / s^2`
+    dyne => 1e-5 * N,
+    /// Energy in erg, which is the same as `dyne * cm`
+    erg => 1e-7 * J,
+    /// Pressure in barye, which is the same as `dyne / cm^2`
+    Ba => 0.1 * Pa,
+    /// Dynamic viscosity in poise
+    P => 0.1 * Pa_s,
+    /// Kinematic viscosity in stokes, which is the same as `cm^2 / s`
+    St => 1e-4 * m2_s,
+}
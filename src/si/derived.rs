@@ -0,0 +1,98 @@
+//! Units that are derived from the SI base units.
+//!
+//! All units defined here are re-exported in the [`units`](crate::units)
+//! and [`values`](crate::values) modules.
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::units::{kg, m, s, Hz, J, N, Pa, W, ONE};
+//!
+//! assert_eq!(Hz, s.inv());
+//! assert_eq!(N, kg * m / (s * s));
+//! assert_eq!(J, kg * m * m / (s * s));
+//! assert_eq!(W, kg * m * m / (s * s * s));
+//! assert_eq!(Pa, kg / (m * s * s));
+//!
+//! use tiny_uom::units::{m2_s, Pa_s};
+//!
+//! let density = kg / (m * m * m);
+//! assert_eq!(Pa * s, Pa_s);
+//! assert_eq!(Pa_s / density, m2_s);
+//!
+//! use tiny_uom::units::{A, C, F, V, Ohm, S};
+//!
+//! assert_eq!(C, A * s);
+//! assert_eq!(V, W / A);
+//! assert_eq!(V / A, Ohm);
+//! assert_eq!(S, Ohm.inv());
+//! assert_eq!(F, C / V);
+//!
+//! use tiny_uom::units::{cd, kat, lm, lx, mol, sr};
+//!
+//! assert_eq!(lm, cd * sr);
+//! assert_eq!(lx, cd * sr / (m * m));
+//! assert_eq!(lx, lm / (m * m));
+//! assert_eq!(kat, mol / s);
+//!
+//! assert_eq!(ONE, m / m);
+//! assert!(ONE.is_dimensionless());
+//!
+//! use tiny_uom::values;
+//!
+//! let e = 5.0 * values::J;
+//! assert_eq!(e, 5.0 * values::kg * values::m * values::m / (values::s * values::s));
+//!
+//! let viscosity = 2.0 * values::Pa * (3.0 * values::s);
+//! assert_eq!(viscosity, 6.0 * values::Pa_s);
+//!
+//! let period = 0.02 * values::s;
+//! assert_eq!(period.recip(), 50.0 * values::Hz);
+//! assert_eq!(1.0 / values::s, 1.0 * values::Hz);
+//! ```
+
+units_impl! {
+    /// The unit of dimensionless quantities, like the ratio of two lengths
+    ///
+    /// Dimensionless quantities can be converted from and into raw numbers using `From`.
+    ONE => NONE,
+    /// Plane angle in radian
+    ///
+    /// Angles are dimensionless in the SI, so this unit has no exponents
+    /// and is equal to every other dimensionless unit, like `m / m`.
+    rad => NONE,
+    /// Solid angle in steradian
+    ///
+    /// Like [`rad`], this unit is dimensionless.
+    sr => NONE,
+    /// Frequency in hertz, which is the same as `1 / s`
+    Hz => Unit { s: -1, ..NONE },
+    /// Force in newton
+    N => Unit { m: 1, kg: 1, s: -2, ..NONE },
+    /// Energy in joule
+    J => Unit { m: 2, kg: 1, s: -2, ..NONE },
+    /// Power in watt
+    W => Unit { m: 2, kg: 1, s: -3, ..NONE },
+    /// Pressure in pascal
+    Pa => Unit { m: -1, kg: 1, s: -2, ..NONE },
+    /// Dynamic viscosity in pascal seconds
+    Pa_s => Unit { m: -1, kg: 1, s: -1, ..NONE },
+    /// Kinematic viscosity in square metre per second
+    m2_s => Unit { m: 2, s: -1, ..NONE },
+    /// Electric charge in coulomb
+    C => Unit { s: 1, A: 1, ..NONE },
+    /// Electric potential in volt
+    V => Unit { m: 2, kg: 1, s: -3, A: -1, ..NONE },
+    /// Electrical resistance in ohm (Ω)
+    Ohm => Unit { m: 2, kg: 1, s: -3, A: -2, ..NONE },
+    /// Electrical conductance in siemens
+    S => Unit { m: -2, kg: -1, s: 3, A: 2, ..NONE },
+    /// Capacitance in farad
+    F => Unit { m: -2, kg: -1, s: 4, A: 2, ..NONE },
+    /// Luminous flux in lumen, which is the same as `cd * sr`
+    lm => Unit { cd: 1, ..NONE },
+    /// Illuminance in lux
+    lx => Unit { m: -2, cd: 1, ..NONE },
+    /// Catalytic activity in katal
+    kat => Unit { s: -1, mol: 1, ..NONE },
+}
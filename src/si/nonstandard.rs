@@ -0,0 +1,33 @@
+//! Units that are not part of the SI, but are accepted for use with it.
+//!
+//! All constants defined here are scaled versions of SI units and
+//! are re-exported in the [`values`](crate::values) module.
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::values::{h, kWh, kg, km, m, s, t, Wh, J, L, W};
+//!
+//! assert_eq!(2.0 * h, 7200.0 * s);
+//! assert_eq!(1000.0 * L, m * m * m);
+//! assert_eq!(1.5 * t, 1500.0 * kg);
+//! assert_eq!(90.0 * km / h, 25.0 * (m / s));
+//! assert_eq!(1.0 * Wh, W * h);
+//! assert_eq!(1.0 * kWh, 1000.0 * Wh);
+//! assert_eq!(1.0 * kWh, 3.6e6 * J);
+//! ```
+
+scaled_impl! {
+    /// Time in minute
+    min => 60.0 * s,
+    /// Time in hour
+    h => 3600.0 * s,
+    /// Volume in litre
+    L => MILLI * m.powi(3),
+    /// Mass in tonne
+    t => KILO * kg,
+    /// Energy in watt-hour
+    Wh => 3600.0 * W.mul(s),
+    /// Energy in kilowatt-hour
+    kWh => 3_600_000.0 * W.mul(s),
+}
@@ -0,0 +1,52 @@
+//! All SI prefixes as plain factors.
+//!
+//! The prefixes can be used to scale any quantity:
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::{prefixes::KILO, values::m};
+//!
+//! assert_eq!(5.0 * KILO * m, 5000.0 * m);
+//! ```
+
+/// The `yotta` prefix (10²⁴)
+pub const YOTTA: f64 = 1e24;
+/// The `zetta` prefix (10²¹)
+pub const ZETTA: f64 = 1e21;
+/// The `exa` prefix (10¹⁸)
+pub const EXA: f64 = 1e18;
+/// The `peta` prefix (10¹⁵)
+pub const PETA: f64 = 1e15;
+/// The `tera` prefix (10¹²)
+pub const TERA: f64 = 1e12;
+/// The `giga` prefix (10⁹)
+pub const GIGA: f64 = 1e9;
+/// The `mega` prefix (10⁶)
+pub const MEGA: f64 = 1e6;
+/// The `kilo` prefix (10³)
+pub const KILO: f64 = 1e3;
+/// The `hecto` prefix (10²)
+pub const HECTO: f64 = 1e2;
+/// The `deca` prefix (10¹)
+pub const DECA: f64 = 1e1;
+/// The `deci` prefix (10⁻¹)
+pub const DECI: f64 = 1e-1;
+/// The `centi` prefix (10⁻²)
+pub const CENTI: f64 = 1e-2;
+/// The `milli` prefix (10⁻³)
+pub const MILLI: f64 = 1e-3;
+/// The `micro` prefix (10⁻⁶)
+pub const MICRO: f64 = 1e-6;
+/// The `nano` prefix (10⁻⁹)
+pub const NANO: f64 = 1e-9;
+/// The `pico` prefix (10⁻¹²)
+pub const PICO: f64 = 1e-12;
+/// The `femto` prefix (10⁻¹⁵)
+pub const FEMTO: f64 = 1e-15;
+/// The `atto` prefix (10⁻¹⁸)
+pub const ATTO: f64 = 1e-18;
+/// The `zepto` prefix (10⁻²¹)
+pub const ZEPTO: f64 = 1e-21;
+/// The `yocto` prefix (10⁻²⁴)
+pub const YOCTO: f64 = 1e-24;
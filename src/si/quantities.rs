@@ -0,0 +1,66 @@
+//! Type aliases for common quantities.
+//!
+//! All aliases use [`Quantity`], which is backed by an `f64`.
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::{
+//!     quantities::{Energy, Mass, Velocity},
+//!     values::{kg, m, s, J},
+//! };
+//!
+//! fn kinetic(mass: Mass, velocity: Velocity) -> Energy {
+//!     0.5 * mass * velocity * velocity
+//! }
+//!
+//! assert_eq!(kinetic(2.0 * kg, 3.0 * (m / s)), 9.0 * J);
+//! ```
+
+use crate::{
+    units::{cd, kg, m, mol, s, Hz, Ohm, Pa, A, C, J, N, ONE, V, W},
+    Quantity,
+};
+
+/// A dimensionless quantity, like the ratio of two lengths
+pub type Dimensionless = Quantity<{ ONE }>;
+/// Length in metre
+pub type Length = Quantity<{ m }>;
+/// Mass in kilogram
+pub type Mass = Quantity<{ kg }>;
+/// Time in seconds
+pub type Time = Quantity<{ s }>;
+/// Electric current in ampere
+pub type ElectricCurrent = Quantity<{ A }>;
+/// Amount of substance in mole
+pub type AmountOfSubstance = Quantity<{ mol }>;
+/// Luminous intensity in candela
+pub type LuminousIntensity = Quantity<{ cd }>;
+/// Area in square metre
+pub type Area = Quantity<{ m.powi(2) }>;
+/// Volume in cubic metre
+pub type Volume = Quantity<{ m.powi(3) }>;
+/// Density in kilogram per cubic metre
+pub type Density = Quantity<{ kg.div(m.powi(3)) }>;
+/// Velocity in metre per second
+pub type Velocity = Quantity<{ m.div(s) }>;
+/// Acceleration in metre per second squared
+pub type Acceleration = Quantity<{ m.div(s.powi(2)) }>;
+/// Momentum in kilogram metre per second
+pub type Momentum = Quantity<{ kg.mul(m).div(s) }>;
+/// Frequency in hertz
+pub type Frequency = Quantity<{ Hz }>;
+/// Force in newton
+pub type Force = Quantity<{ N }>;
+/// Energy in joule
+pub type Energy = Quantity<{ J }>;
+/// Power in watt
+pub type Power = Quantity<{ W }>;
+/// Pressure in pascal
+pub type Pressure = Quantity<{ Pa }>;
+/// Electric charge in coulomb
+pub type ElectricCharge = Quantity<{ C }>;
+/// Electric potential in volt
+pub type ElectricPotential = Quantity<{ V }>;
+/// Electrical resistance in ohm
+pub type Resistance = Quantity<{ Ohm }>;
@@ -0,0 +1,43 @@
+//! Scaled versions of the SI units.
+//!
+//! All constants defined here are re-exported in the [`values`](crate::values) module.
+//!
+//! ```
+//! # #![feature(const_generics, const_evaluatable_checked)]
+//! # #![allow(incomplete_features)]
+//! use tiny_uom::values::{g, kg, km, m, mg, ms, s, ug};
+//!
+//! assert_eq!(1.0 * km, 1000.0 * m);
+//! assert_eq!(2.0 * ms, 0.002 * s);
+//! assert_eq!(500.0 * g, 0.5 * kg);
+//! assert_eq!(1.0 * mg, 0.001 * g);
+//! assert_eq!(1.0 * ug, 0.000_000_001 * kg);
+//! ```
+
+scaled_impl! {
+    /// Length in kilometre
+    km => KILO * m,
+    /// Length in centimetre
+    cm => CENTI * m,
+    /// Length in millimetre
+    mm => MILLI * m,
+    /// Length in micrometre
+    um => MICRO * m,
+    /// Length in nanometre
+    nm => NANO * m,
+    /// Mass in gram
+    ///
+    /// The SI base unit of mass is the kilogram, not the gram,
+    /// so the value of this constant is `0.001`.
+    g => MILLI * kg,
+    /// Mass in milligram
+    mg => MICRO * kg,
+    /// Mass in microgram
+    ug => NANO * kg,
+    /// Time in millisecond
+    ms => MILLI * s,
+    /// Time in microsecond
+    us => MICRO * s,
+    /// Time in nanosecond
+    ns => NANO * s,
+}
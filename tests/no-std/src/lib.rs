@@ -0,0 +1,17 @@
+//! This crate only exists to make sure that `tiny-uom` compiles in a `#![no_std]` environment.
+//!
+//! Run `cargo build` inside this directory to check it.
+#![no_std]
+#![allow(incomplete_features)]
+#![feature(const_generics, const_evaluatable_checked)]
+
+use tiny_uom::{
+    units,
+    values::{m, s},
+    Quantity,
+};
+
+/// Calculate the velocity using only `core` functionality.
+pub fn velocity(distance: f64, time: f64) -> Quantity<{ units::m.div(units::s) }> {
+    (distance * m) / (time * s)
+}